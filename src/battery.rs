@@ -0,0 +1,306 @@
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use anyhow::Result;
+use colored::*;
+use serde::Serialize;
+use tokio::task::spawn_blocking;
+
+/// 电源设备目录
+pub const SYS_CLASS_POWER_SUPPLY: &str = "/sys/class/power_supply";
+
+/// 电池的充电状态
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatteryState {
+    Charging,
+    Discharging,
+    Full,
+    NotCharging,
+    Unknown,
+}
+
+impl From<&str> for BatteryState {
+    fn from(status: &str) -> Self {
+        match status {
+            "Charging" => BatteryState::Charging,
+            "Discharging" => BatteryState::Discharging,
+            "Full" => BatteryState::Full,
+            "Not charging" => BatteryState::NotCharging,
+            _ => BatteryState::Unknown,
+        }
+    }
+}
+
+impl Display for BatteryState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BatteryState::Charging => write!(f, "{}", "charging".green()),
+            BatteryState::Discharging => write!(f, "{}", "discharging".yellow()),
+            BatteryState::Full => write!(f, "{}", "full".green()),
+            BatteryState::NotCharging => write!(f, "not charging"),
+            BatteryState::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// 一块电池
+#[derive(Serialize, Debug)]
+pub struct Battery {
+    /// 设备名称，例如 BAT0
+    pub name: String,
+
+    /// 制造商和型号
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+
+    pub state: BatteryState,
+
+    /// 剩余电量的百分比
+    pub charge_percent: Option<f32>,
+
+    /// 健康度，即当前满电容量占设计容量的百分比
+    pub health_percent: Option<f32>,
+
+    /// 充放电循环次数
+    pub cycle_count: Option<u32>,
+
+    /// 按当前功耗估计的剩余使用时间，仅在放电时可用
+    pub time_to_empty_seconds: Option<u64>,
+}
+
+impl Display for Battery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\t{}", self.name.bold(), self.state)?;
+
+        if let Some(charge) = self.charge_percent {
+            let text = format!("{:.0}%", charge);
+            // 与RAM的颜色阈值对称：电量越低越危险
+            let colored = match charge {
+                _ if charge < 10.0 => text.red(),
+                _ if charge < 30.0 => text.yellow(),
+                _ => text.green(),
+            };
+            write!(f, "\t{}", colored)?;
+        }
+        if let Some(seconds) = self.time_to_empty_seconds {
+            write!(f, "\t{}h {:02}m remaining", seconds / 3600, seconds % 3600 / 60)?;
+        }
+        if let Some(health) = self.health_percent {
+            write!(f, "\thealth {:.0}%", health)?;
+        }
+        if let Some(cycles) = self.cycle_count {
+            write!(f, "\t{} cycles", cycles)?;
+        }
+        if let Some(model) = &self.model {
+            write!(f, "\t({})", model)?;
+        }
+        Ok(())
+    }
+}
+
+/// 电池和交流电源的状态
+#[derive(Serialize, Debug)]
+pub struct PowerSupply {
+    /// 是否连接了交流或USB等外部电源，没有外部电源设备时为空
+    pub ac_online: Option<bool>,
+
+    pub batteries: Vec<Battery>,
+}
+
+impl Display for PowerSupply {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.ac_online {
+            Some(true) => writeln!(f, "{}\t{}", "AC".bold(), "online".green())?,
+            Some(false) => writeln!(f, "{}\t{}", "AC".bold(), "offline".yellow())?,
+            None => {}
+        }
+        if self.batteries.is_empty() {
+            return write!(f, "no battery");
+        }
+
+        let batteries = self.batteries.iter().map(ToString::to_string).collect::<Vec<String>>();
+        write!(f, "{}", batteries.join("\n"))
+    }
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    std::fs::read_to_string(path).ok().map(|contents| contents.trim().to_string())
+}
+
+fn read_number(path: &Path) -> Option<f64> {
+    read_trimmed(path)?.parse().ok()
+}
+
+/// 读取一块电池。内核按电池类型提供以µWh为单位的 `energy_*`
+/// 或以µAh为单位的 `charge_*`，两种单位不混用。
+fn battery(device: &Path) -> Battery {
+    let attribute = |name: &str| read_number(&device.join(name));
+    let (now, full, design, rate) = match attribute("energy_full") {
+        Some(full) => (attribute("energy_now"), Some(full), attribute("energy_full_design"), attribute("power_now")),
+        None => (attribute("charge_now"), attribute("charge_full"), attribute("charge_full_design"), attribute("current_now")),
+    };
+
+    let state = read_trimmed(&device.join("status")).as_deref().map_or(BatteryState::Unknown, BatteryState::from);
+
+    let charge_percent = attribute("capacity")
+        .or_else(|| Some(now? / full? * 100.0))
+        .map(|percent| percent as f32);
+
+    let health_percent = match (full, design) {
+        (Some(full), Some(design)) if design > 0.0 => Some((full / design * 100.0) as f32),
+        _ => None,
+    };
+
+    let time_to_empty_seconds = match (state, now, rate) {
+        // 部分驱动在放电时报告负的功率或电流
+        (BatteryState::Discharging, Some(now), Some(rate)) if rate != 0.0 => Some((now / rate.abs() * 3600.0) as u64),
+        _ => None,
+    };
+
+    let model = [read_trimmed(&device.join("manufacturer")), read_trimmed(&device.join("model_name"))]
+        .into_iter()
+        .flatten()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>();
+
+    Battery {
+        name: device.file_name().unwrap_or_default().to_string_lossy().to_string(),
+        model: (!model.is_empty()).then(|| model.join(" ")),
+        state,
+        charge_percent,
+        health_percent,
+        cycle_count: attribute("cycle_count").map(|cycles| cycles as u32).filter(|cycles| *cycles > 0),
+        time_to_empty_seconds,
+    }
+}
+
+/// 读取 `root` 下的电源设备。类型为 Battery 的设备是电池；
+/// 其他带有 `online` 属性的设备（Mains、USB、USB_PD 等）都视为外部电源。
+pub fn power_supply(root: &Path) -> PowerSupply {
+    let mut devices = match std::fs::read_dir(root) {
+        Ok(entries) => entries.filter_map(|entry| entry.ok()).map(|entry| entry.path()).collect::<Vec<_>>(),
+        Err(_) => Vec::new(),
+    };
+    devices.sort();
+
+    let mut ac_online = None;
+    let mut batteries = Vec::new();
+    for device in devices {
+        match read_trimmed(&device.join("type")).as_deref() {
+            Some("Battery") => batteries.push(battery(&device)),
+            Some(_) => {
+                let Some(online) = read_trimmed(&device.join("online")) else { continue };
+                ac_online = Some(ac_online.unwrap_or(false) || online == "1");
+            }
+            None => {}
+        }
+    }
+
+    PowerSupply { ac_online, batteries }
+}
+
+/// 返回电池和交流电源的状态。台式机和服务器通常没有电池，此时电池列表为空。
+pub async fn battery_status() -> Result<PowerSupply> {
+    Ok(spawn_blocking(|| power_supply(Path::new(SYS_CLASS_POWER_SUPPLY))).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn device(root: &Path, name: &str, attributes: &[(&str, &str)]) {
+        let device = root.join(name);
+        fs::create_dir_all(&device).unwrap();
+        for (attribute, value) in attributes {
+            fs::write(device.join(attribute), format!("{}\n", value)).unwrap();
+        }
+    }
+
+    #[test]
+    fn power_supply_reads_energy_batteries() {
+        let root = tempfile::tempdir().unwrap();
+        device(root.path(), "AC", &[("type", "Mains"), ("online", "0")]);
+        device(root.path(), "BAT0", &[
+            ("type", "Battery"),
+            ("status", "Discharging"),
+            ("energy_now", "25000000"),
+            ("energy_full", "50000000"),
+            ("energy_full_design", "62500000"),
+            ("power_now", "-10000000"),
+            ("cycle_count", "120"),
+            ("manufacturer", "SMP"),
+            ("model_name", "5B10W13930"),
+        ]);
+
+        let supply = power_supply(root.path());
+        assert_eq!(supply.ac_online, Some(false));
+        assert_eq!(supply.batteries.len(), 1);
+
+        let battery = &supply.batteries[0];
+        assert_eq!(battery.name, "BAT0");
+        assert_eq!(battery.state, BatteryState::Discharging);
+        assert_eq!(battery.charge_percent, Some(50.0));
+        assert_eq!(battery.health_percent, Some(80.0));
+        assert_eq!(battery.cycle_count, Some(120));
+        assert_eq!(battery.time_to_empty_seconds, Some(9000));
+        assert_eq!(battery.model.as_deref(), Some("SMP 5B10W13930"));
+    }
+
+    #[test]
+    fn power_supply_reads_charge_batteries() {
+        let root = tempfile::tempdir().unwrap();
+        device(root.path(), "BAT1", &[
+            ("type", "Battery"),
+            ("status", "Charging"),
+            ("capacity", "75"),
+            ("charge_now", "3000000"),
+            ("charge_full", "4000000"),
+            ("charge_full_design", "4000000"),
+            ("current_now", "1500000"),
+            ("cycle_count", "0"),
+        ]);
+
+        let battery = &power_supply(root.path()).batteries[0];
+        assert_eq!(battery.state, BatteryState::Charging);
+        assert_eq!(battery.charge_percent, Some(75.0));
+        assert_eq!(battery.health_percent, Some(100.0));
+        assert_eq!(battery.cycle_count, None);
+        assert_eq!(battery.time_to_empty_seconds, None);
+        assert_eq!(battery.model, None);
+    }
+
+    #[test]
+    fn power_supply_without_health_data() {
+        let root = tempfile::tempdir().unwrap();
+        device(root.path(), "BAT0", &[("type", "Battery"), ("status", "Full"), ("energy_full", "50000000")]);
+
+        let battery = &power_supply(root.path()).batteries[0];
+        assert_eq!(battery.health_percent, None);
+        assert_eq!(battery.charge_percent, None);
+    }
+
+    #[test]
+    fn power_supply_counts_usb_supplies_as_external_power() {
+        let root = tempfile::tempdir().unwrap();
+        device(root.path(), "AC", &[("type", "Mains"), ("online", "0")]);
+        device(root.path(), "ucsi-source-psy-USBC000:001", &[("type", "USB"), ("online", "1")]);
+
+        let supply = power_supply(root.path());
+        assert_eq!(supply.ac_online, Some(true));
+        assert!(supply.batteries.is_empty());
+    }
+
+    #[test]
+    fn power_supply_without_battery() {
+        let root = tempfile::tempdir().unwrap();
+        let supply = power_supply(root.path());
+        assert_eq!(supply.ac_online, None);
+        assert!(supply.batteries.is_empty());
+        assert_eq!(supply.to_string(), "no battery");
+
+        device(root.path(), "USB", &[("type", "USB")]);
+        assert_eq!(power_supply(root.path()).ac_online, None);
+        assert!(power_supply(&root.path().join("missing")).batteries.is_empty());
+    }
+}
@@ -0,0 +1,413 @@
+use std::fmt::{Display, Formatter};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use anyhow::{bail, Context, Result};
+use colored::*;
+use serde::Serialize;
+use tokio::net::{TcpStream, ToSocketAddrs, UdpSocket};
+use tokio::task::spawn_blocking;
+use crate::network;
+
+/// 邻居表中没有网关时，向这个端口发送一个UDP数据报，促使内核解析网关的硬件地址。
+/// 数据报本身不需要任何应答。
+pub const GATEWAY_SOLICIT_PORT: u16 = 9;
+
+/// 等待内核解析网关硬件地址时，两次读取邻居表之间的间隔
+const NEIGHBOR_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 默认用于检查DNS解析的名称
+pub const DEFAULT_DNS_CHECK_NAME: &str = "example.com";
+
+/// 默认用于检查TCP可达性的端点
+pub const DEFAULT_ENDPOINT: &str = "example.com:443";
+
+/// 连通性检查所针对的网络层次，按检查顺序排列
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Layer {
+    Link,
+    Gateway,
+    Dns,
+    Endpoint,
+}
+
+impl Display for Layer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Layer::Link => write!(f, "link"),
+            Layer::Gateway => write!(f, "gateway"),
+            Layer::Dns => write!(f, "dns"),
+            Layer::Endpoint => write!(f, "endpoint"),
+        }
+    }
+}
+
+/// 单项检查的结果
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Pass,
+    Fail,
+    Skipped,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "{}", "pass".green()),
+            Status::Fail => write!(f, "{}", "fail".red().bold()),
+            Status::Skipped => write!(f, "{}", "skipped".bright_black()),
+        }
+    }
+}
+
+/// 某一层的检查结果
+#[derive(Serialize, Debug)]
+pub struct Check {
+    pub layer: Layer,
+
+    pub status: Status,
+
+    /// 检查结果的说明
+    pub detail: String,
+
+    /// 检查耗时，单位为毫秒
+    pub latency_ms: Option<f64>,
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\t{}\t{}", self.layer.to_string().bold(), self.status, self.detail)?;
+        if let Some(latency_ms) = self.latency_ms {
+            write!(f, " ({})", format!("{:.1} ms", latency_ms).cyan())?;
+        }
+        Ok(())
+    }
+}
+
+/// 连通性诊断的结果
+#[derive(Serialize, Debug)]
+pub struct Connectivity {
+    /// 第一个失败的层次，全部通过时为空
+    pub failed_layer: Option<Layer>,
+
+    pub checks: Vec<Check>,
+}
+
+impl Display for Connectivity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for check in &self.checks {
+            writeln!(f, "{}", check)?;
+        }
+        match self.failed_layer {
+            Some(layer) => write!(f, "{}", format!("connectivity fails at the {} layer", layer).red().bold()),
+            None => write!(f, "{}", "connectivity ok".green().bold()),
+        }
+    }
+}
+
+/// 连通性诊断的选项
+#[derive(Clone, Debug)]
+pub struct Options {
+    /// 用于检查DNS解析的名称
+    pub dns_name: String,
+
+    /// 用于检查TCP可达性的端点，格式为 `host:port`
+    pub endpoint: String,
+
+    /// 每项网络检查的超时时间
+    pub timeout: Duration,
+}
+
+/// 依次检查默认路由接口的链路状态、网关可达性、DNS解析和TCP端点的可达性。
+/// 链路或DNS失败后，之后的层次不再检查；网关可能不响应探测，
+/// 因此网关失败时仍会继续检查DNS和端点。
+pub async fn diagnose(options: &Options) -> Result<Connectivity> {
+    let mut checks = Vec::new();
+    let mut failed_layer = None;
+    let mut stopped = false;
+
+    let routes = network::routes().await?;
+    let default_route = routes.default_route().cloned();
+
+    for layer in [Layer::Link, Layer::Gateway, Layer::Dns, Layer::Endpoint] {
+        if stopped {
+            checks.push(Check { layer, status: Status::Skipped, detail: String::new(), latency_ms: None });
+            continue;
+        }
+
+        let start = Instant::now();
+        let outcome = match layer {
+            Layer::Link => check_link(default_route.as_ref()).await,
+            Layer::Gateway => match default_route.as_ref().and_then(|route| Some((route.gateway?, &route.interface))) {
+                Some((gateway, interface)) => check_gateway(gateway, interface, options.timeout).await,
+                None => Err(anyhow::anyhow!("no default gateway")),
+            },
+            Layer::Dns => check_dns(&options.dns_name, options.timeout).await,
+            Layer::Endpoint => check_endpoint(&options.endpoint, options.timeout).await,
+        };
+        let latency_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+        checks.push(match outcome {
+            Ok(detail) => Check {
+                layer,
+                status: Status::Pass,
+                detail,
+                latency_ms: (layer != Layer::Link).then_some(latency_ms),
+            },
+            Err(e) => {
+                failed_layer = failed_layer.or(Some(layer));
+                stopped = layer != Layer::Gateway;
+                Check { layer, status: Status::Fail, detail: format!("{:#}", e), latency_ms: None }
+            }
+        });
+    }
+
+    Ok(Connectivity { failed_layer, checks })
+}
+
+/// 检查默认路由所在接口是否存在、已启用并配置了地址
+async fn check_link(default_route: Option<&network::Route>) -> Result<String> {
+    let Some(route) = default_route else {
+        bail!("no default route");
+    };
+
+    let name = &route.interface;
+    let interfaces = network::interfaces().await?;
+    if !interfaces.iter().any(|interface| &interface.name == name) {
+        bail!("{} has no addresses", name);
+    }
+
+    let path = Path::new(network::SYS_CLASS_NET).join(name).join("operstate");
+    let operstate = spawn_blocking(move || std::fs::read_to_string(path))
+        .await?
+        .with_context(|| format!("reading the link state of {} failed", name))?;
+
+    match operstate.trim() {
+        // 虚拟接口和回环接口通常报告 unknown
+        state @ ("up" | "unknown") => Ok(format!("{} is {}", name, state)),
+        state => bail!("{} is {}", name, state),
+    }
+}
+
+/// 检查网关在邻居表中是否有可用的表项。
+/// 没有时发送一个UDP数据报触发ARP或邻居发现，并在超时前反复读取邻居表。
+async fn check_gateway(gateway: IpAddr, interface: &str, timeout: Duration) -> Result<String> {
+    if let Some(state) = gateway_state(gateway, interface).await? {
+        return Ok(format!("{} is {} on {}", gateway, state, interface));
+    }
+
+    solicit_gateway(gateway, interface).await
+        .with_context(|| format!("sending a probe to {} failed", gateway))?;
+
+    let deadline = Instant::now() + timeout;
+    while Instant::now() < deadline {
+        tokio::time::sleep(NEIGHBOR_POLL_INTERVAL).await;
+        if let Some(state) = gateway_state(gateway, interface).await? {
+            return Ok(format!("{} is {} on {}", gateway, state, interface));
+        }
+    }
+    bail!("{} did not answer address resolution within {} seconds", gateway, timeout.as_secs_f64())
+}
+
+/// 返回网关在邻居表中的状态，没有表项或表项不可用时为空
+async fn gateway_state(gateway: IpAddr, interface: &str) -> Result<Option<String>> {
+    let neighbors = network::neighbors().await?;
+    Ok(neighbors
+        .into_iter()
+        .find(|neighbor| neighbor.ip == gateway && neighbor.interface == interface)
+        .map(|neighbor| neighbor.state)
+        .filter(|state| !matches!(state.as_str(), "incomplete" | "failed" | "none")))
+}
+
+/// 向网关发送一个UDP数据报。IPv6链路本地地址需要接口的 scope id 才能发送。
+async fn solicit_gateway(gateway: IpAddr, interface: &str) -> Result<()> {
+    let (bind_addr, target): (SocketAddr, SocketAddr) = match gateway {
+        IpAddr::V4(v4) => ((Ipv4Addr::UNSPECIFIED, 0).into(), (v4, GATEWAY_SOLICIT_PORT).into()),
+        IpAddr::V6(v6) => {
+            let path = Path::new(network::SYS_CLASS_NET).join(interface).join("ifindex");
+            let scope_id = spawn_blocking(move || std::fs::read_to_string(path))
+                .await?
+                .ok()
+                .and_then(|index| index.trim().parse().ok())
+                .unwrap_or(0);
+            ((Ipv6Addr::UNSPECIFIED, 0).into(), SocketAddrV6::new(v6, GATEWAY_SOLICIT_PORT, 0, scope_id).into())
+        }
+    };
+
+    let socket = UdpSocket::bind(bind_addr).await?;
+    socket.send_to(&[], target).await?;
+    Ok(())
+}
+
+/// 在超时前建立TCP连接，返回对端地址。对方拒绝连接视为失败。
+pub async fn check_tcp<A: ToSocketAddrs + Display>(address: A, timeout: Duration) -> Result<SocketAddr> {
+    let connect = async {
+        let stream = TcpStream::connect(&address).await?;
+        stream.peer_addr()
+    };
+
+    match tokio::time::timeout(timeout, connect).await {
+        Ok(Ok(peer)) => Ok(peer),
+        Ok(Err(e)) => Err(e).with_context(|| format!("connecting to {} failed", address)),
+        Err(_) => bail!("connecting to {} timed out after {} seconds", address, timeout.as_secs_f64()),
+    }
+}
+
+/// 使用系统配置的DNS服务器解析名称
+async fn check_dns(name: &str, timeout: Duration) -> Result<String> {
+    let config = network::list_dns_servers().await?;
+    let mut servers: Vec<SocketAddr> = Vec::new();
+    for nameserver in &config.nameservers {
+        let server = SocketAddr::new(nameserver.address, nameserver.port);
+        if !servers.contains(&server) {
+            servers.push(server);
+        }
+    }
+
+    check_dns_with(name, &servers, timeout).await
+}
+
+/// 通过UDP依次直接查询 `servers`，返回第一个应答。
+/// 不经过系统解析器，因此 `/etc/hosts` 中的条目不会让这一层在没有DNS服务器应答时通过。
+async fn check_dns_with(name: &str, servers: &[SocketAddr], timeout: Duration) -> Result<String> {
+    if servers.is_empty() {
+        bail!("no dns servers configured");
+    }
+
+    let mut failures = Vec::new();
+    for &server in servers {
+        let dns_server = network::DnsServer::new(server.ip(), network::DnsTransport::Udp).with_port(Some(server.port()));
+        let lookup = network::resolve(name, network::RecordKind::A, Some(&dns_server), network::DnsTransport::Udp);
+
+        match tokio::time::timeout(timeout, lookup).await {
+            Ok(Ok(resolution)) => match resolution.answers.first() {
+                Some(answer) => return Ok(format!("{} resolves to {} via {}", name, answer.data, server)),
+                None => failures.push(format!("{}: {}", server, resolution.status)),
+            },
+            Ok(Err(e)) => failures.push(format!("{}: {:#}", server, e)),
+            Err(_) => failures.push(format!("{}: timed out", server)),
+        }
+    }
+
+    bail!("{} did not resolve ({})", name, failures.join(", "))
+}
+
+/// 解析端点并建立TCP连接
+pub async fn check_endpoint(endpoint: &str, timeout: Duration) -> Result<String> {
+    let peer = check_tcp(endpoint, timeout).await?;
+    Ok(format!("connected to {} ({})", endpoint, peer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    /// 返回一个当前没有监听的本地端口
+    async fn closed_port() -> SocketAddr {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        listener.local_addr().unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_tcp_connects_to_a_listening_port() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        assert_eq!(check_tcp(address, Duration::from_secs(3)).await.unwrap(), address);
+    }
+
+    #[tokio::test]
+    async fn check_tcp_fails_on_a_closed_port() {
+        let address = closed_port().await;
+
+        let error = check_tcp(address, Duration::from_secs(3)).await.unwrap_err();
+        assert!(error.to_string().contains("failed"), "{:#}", error);
+    }
+
+    #[tokio::test]
+    async fn check_endpoint_reports_the_peer_address() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let endpoint = listener.local_addr().unwrap().to_string();
+
+        let detail = check_endpoint(&endpoint, Duration::from_secs(3)).await.unwrap();
+        assert_eq!(detail, format!("connected to {} ({})", endpoint, endpoint));
+    }
+
+    #[tokio::test]
+    async fn check_endpoint_fails_on_a_closed_port() {
+        let endpoint = closed_port().await.to_string();
+
+        assert!(check_endpoint(&endpoint, Duration::from_secs(3)).await.is_err());
+    }
+
+    /// 在 127.0.0.1 的随机端口上启动一个UDP DNS服务器，对每个查询应答 NXDOMAIN 或一条A记录
+    async fn dns_stand_in(answer: Option<Ipv4Addr>) -> SocketAddr {
+        use trust_dns_resolver::proto::op::{Message, MessageType, ResponseCode};
+        use trust_dns_resolver::proto::rr::{RData, Record};
+        use trust_dns_resolver::proto::serialize::binary::BinEncodable;
+
+        let socket = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let address = socket.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buffer = [0u8; 512];
+            loop {
+                let (len, client) = socket.recv_from(&mut buffer).await.unwrap();
+                let query = Message::from_vec(&buffer[..len]).unwrap();
+                let mut response = Message::new();
+                response
+                    .set_id(query.id())
+                    .set_message_type(MessageType::Response)
+                    .set_recursion_available(true)
+                    .add_queries(query.queries().to_vec());
+                match answer {
+                    Some(ip) => {
+                        for question in query.queries() {
+                            response.add_answer(Record::from_rdata(question.name().clone(), 60, RData::A(ip)));
+                        }
+                    }
+                    None => {
+                        response.set_response_code(ResponseCode::NXDomain);
+                    }
+                }
+                socket.send_to(&response.to_bytes().unwrap(), client).await.unwrap();
+            }
+        });
+        address
+    }
+
+    #[tokio::test]
+    async fn check_dns_reports_the_answer_of_the_first_server() {
+        let failing = dns_stand_in(None).await;
+        let answering = dns_stand_in(Some(Ipv4Addr::new(192, 0, 2, 7))).await;
+
+        let detail = check_dns_with("stand-in.test", &[failing, answering], Duration::from_secs(3)).await.unwrap();
+        assert_eq!(detail, format!("stand-in.test resolves to 192.0.2.7 via {}", answering));
+    }
+
+    #[tokio::test]
+    async fn check_dns_is_not_answered_by_the_hosts_file() {
+        // 取 /etc/hosts 中的一个IPv4名称：系统解析器会从 hosts 文件应答它，DNS服务器却不会
+        let hosts = crate::hosts::read_hosts(Path::new(crate::hosts::ETC_HOSTS), Path::new(crate::hosts::ETC_NSSWITCH_CONF)).unwrap();
+        let name = hosts
+            .entries
+            .iter()
+            .filter(|entry| entry.address.is_ipv4())
+            .flat_map(|entry| entry.names.iter())
+            .find(|name| !name.eq_ignore_ascii_case("localhost") && !name.to_ascii_lowercase().ends_with(".localhost"))
+            .expect("this test needs an IPv4 entry other than localhost in /etc/hosts")
+            .clone();
+
+        let server = dns_stand_in(None).await;
+        let error = check_dns_with(&name, &[server], Duration::from_secs(3)).await.unwrap_err();
+        assert_eq!(error.to_string(), format!("{} did not resolve ({}: NXDOMAIN)", name, server));
+    }
+
+    #[tokio::test]
+    async fn check_dns_fails_without_servers() {
+        let error = check_dns_with("example.com", &[], Duration::from_secs(1)).await.unwrap_err();
+        assert_eq!(error.to_string(), "no dns servers configured");
+    }
+}
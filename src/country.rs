@@ -1,4 +1,11 @@
+use std::fmt::{Display, Formatter};
+use anyhow::{bail, Result};
+use colored::*;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
+
+/// ISO 3166-1 二位字母国家代码
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum CountryCode {
     Unknown,
     AX,
@@ -252,6 +259,1832 @@ pub enum CountryCode {
     ZW,
 }
 
+impl CountryCode {
+    /// 所有已知的国家和地区，不包括 `Unknown`
+    pub const ALL: [CountryCode; 249] = [
+        CountryCode::AX,
+        CountryCode::AL,
+        CountryCode::AF,
+        CountryCode::DZ,
+        CountryCode::AS,
+        CountryCode::AD,
+        CountryCode::AO,
+        CountryCode::AI,
+        CountryCode::AQ,
+        CountryCode::AG,
+        CountryCode::AR,
+        CountryCode::AM,
+        CountryCode::AW,
+        CountryCode::AU,
+        CountryCode::AT,
+        CountryCode::AZ,
+        CountryCode::BS,
+        CountryCode::BH,
+        CountryCode::BD,
+        CountryCode::BB,
+        CountryCode::BY,
+        CountryCode::BE,
+        CountryCode::BZ,
+        CountryCode::BJ,
+        CountryCode::BM,
+        CountryCode::BT,
+        CountryCode::BO,
+        CountryCode::BQ,
+        CountryCode::BA,
+        CountryCode::BW,
+        CountryCode::BV,
+        CountryCode::BR,
+        CountryCode::IO,
+        CountryCode::BN,
+        CountryCode::BG,
+        CountryCode::BF,
+        CountryCode::BI,
+        CountryCode::CV,
+        CountryCode::KH,
+        CountryCode::CM,
+        CountryCode::CA,
+        CountryCode::KY,
+        CountryCode::CF,
+        CountryCode::TD,
+        CountryCode::CL,
+        CountryCode::CN,
+        CountryCode::CX,
+        CountryCode::CC,
+        CountryCode::CO,
+        CountryCode::KM,
+        CountryCode::CG,
+        CountryCode::CD,
+        CountryCode::CK,
+        CountryCode::CR,
+        CountryCode::CI,
+        CountryCode::HR,
+        CountryCode::CU,
+        CountryCode::CW,
+        CountryCode::CY,
+        CountryCode::CZ,
+        CountryCode::DK,
+        CountryCode::DJ,
+        CountryCode::DM,
+        CountryCode::DO,
+        CountryCode::EC,
+        CountryCode::EG,
+        CountryCode::SV,
+        CountryCode::GQ,
+        CountryCode::ER,
+        CountryCode::EE,
+        CountryCode::ET,
+        CountryCode::FK,
+        CountryCode::FO,
+        CountryCode::FJ,
+        CountryCode::FI,
+        CountryCode::FR,
+        CountryCode::GF,
+        CountryCode::PF,
+        CountryCode::TF,
+        CountryCode::GA,
+        CountryCode::GM,
+        CountryCode::GE,
+        CountryCode::DE,
+        CountryCode::GH,
+        CountryCode::GI,
+        CountryCode::GR,
+        CountryCode::GL,
+        CountryCode::GD,
+        CountryCode::GP,
+        CountryCode::GU,
+        CountryCode::GT,
+        CountryCode::GG,
+        CountryCode::GN,
+        CountryCode::GW,
+        CountryCode::GY,
+        CountryCode::HT,
+        CountryCode::HM,
+        CountryCode::VA,
+        CountryCode::HN,
+        CountryCode::HK,
+        CountryCode::HU,
+        CountryCode::IS,
+        CountryCode::IN,
+        CountryCode::ID,
+        CountryCode::IR,
+        CountryCode::IQ,
+        CountryCode::IE,
+        CountryCode::IM,
+        CountryCode::IL,
+        CountryCode::IT,
+        CountryCode::JM,
+        CountryCode::JP,
+        CountryCode::JE,
+        CountryCode::JO,
+        CountryCode::KZ,
+        CountryCode::KE,
+        CountryCode::KI,
+        CountryCode::KP,
+        CountryCode::KR,
+        CountryCode::KW,
+        CountryCode::KG,
+        CountryCode::LA,
+        CountryCode::LV,
+        CountryCode::LB,
+        CountryCode::LS,
+        CountryCode::LR,
+        CountryCode::LY,
+        CountryCode::LI,
+        CountryCode::LT,
+        CountryCode::LU,
+        CountryCode::MO,
+        CountryCode::MK,
+        CountryCode::MG,
+        CountryCode::MW,
+        CountryCode::MY,
+        CountryCode::MV,
+        CountryCode::ML,
+        CountryCode::MT,
+        CountryCode::MH,
+        CountryCode::MQ,
+        CountryCode::MR,
+        CountryCode::MU,
+        CountryCode::YT,
+        CountryCode::MX,
+        CountryCode::FM,
+        CountryCode::MD,
+        CountryCode::MC,
+        CountryCode::MN,
+        CountryCode::ME,
+        CountryCode::MS,
+        CountryCode::MA,
+        CountryCode::MZ,
+        CountryCode::MM,
+        CountryCode::NA,
+        CountryCode::NR,
+        CountryCode::NP,
+        CountryCode::NL,
+        CountryCode::NC,
+        CountryCode::NZ,
+        CountryCode::NI,
+        CountryCode::NE,
+        CountryCode::NG,
+        CountryCode::NU,
+        CountryCode::NF,
+        CountryCode::MP,
+        CountryCode::NO,
+        CountryCode::OM,
+        CountryCode::PK,
+        CountryCode::PW,
+        CountryCode::PS,
+        CountryCode::PA,
+        CountryCode::PG,
+        CountryCode::PY,
+        CountryCode::PE,
+        CountryCode::PH,
+        CountryCode::PN,
+        CountryCode::PL,
+        CountryCode::PT,
+        CountryCode::PR,
+        CountryCode::QA,
+        CountryCode::RE,
+        CountryCode::RO,
+        CountryCode::RU,
+        CountryCode::RW,
+        CountryCode::BL,
+        CountryCode::SH,
+        CountryCode::KN,
+        CountryCode::LC,
+        CountryCode::MF,
+        CountryCode::PM,
+        CountryCode::VC,
+        CountryCode::WS,
+        CountryCode::SM,
+        CountryCode::ST,
+        CountryCode::SA,
+        CountryCode::SN,
+        CountryCode::RS,
+        CountryCode::SC,
+        CountryCode::SL,
+        CountryCode::SG,
+        CountryCode::SX,
+        CountryCode::SK,
+        CountryCode::SI,
+        CountryCode::SB,
+        CountryCode::SO,
+        CountryCode::ZA,
+        CountryCode::GS,
+        CountryCode::SS,
+        CountryCode::ES,
+        CountryCode::LK,
+        CountryCode::SD,
+        CountryCode::SR,
+        CountryCode::SJ,
+        CountryCode::SZ,
+        CountryCode::SE,
+        CountryCode::CH,
+        CountryCode::SY,
+        CountryCode::TW,
+        CountryCode::TJ,
+        CountryCode::TZ,
+        CountryCode::TH,
+        CountryCode::TL,
+        CountryCode::TG,
+        CountryCode::TK,
+        CountryCode::TO,
+        CountryCode::TT,
+        CountryCode::TN,
+        CountryCode::TR,
+        CountryCode::TM,
+        CountryCode::TC,
+        CountryCode::TV,
+        CountryCode::UG,
+        CountryCode::UA,
+        CountryCode::AE,
+        CountryCode::GB,
+        CountryCode::US,
+        CountryCode::UM,
+        CountryCode::UY,
+        CountryCode::UZ,
+        CountryCode::VU,
+        CountryCode::VE,
+        CountryCode::VN,
+        CountryCode::VG,
+        CountryCode::VI,
+        CountryCode::WF,
+        CountryCode::EH,
+        CountryCode::YE,
+        CountryCode::ZM,
+        CountryCode::ZW,
+    ];
+
+    /// 国家或地区的英文名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            CountryCode::AX => "Åland Islands",
+            CountryCode::AL => "Albania",
+            CountryCode::AF => "Afghanistan",
+            CountryCode::DZ => "Algeria",
+            CountryCode::AS => "American Samoa",
+            CountryCode::AD => "Andorra",
+            CountryCode::AO => "Angola",
+            CountryCode::AI => "Anguilla",
+            CountryCode::AQ => "Antarctica",
+            CountryCode::AG => "Antigua and Barbuda",
+            CountryCode::AR => "Argentina",
+            CountryCode::AM => "Armenia",
+            CountryCode::AW => "Aruba",
+            CountryCode::AU => "Australia",
+            CountryCode::AT => "Austria",
+            CountryCode::AZ => "Azerbaijan",
+            CountryCode::BS => "Bahamas",
+            CountryCode::BH => "Bahrain",
+            CountryCode::BD => "Bangladesh",
+            CountryCode::BB => "Barbados",
+            CountryCode::BY => "Belarus",
+            CountryCode::BE => "Belgium",
+            CountryCode::BZ => "Belize",
+            CountryCode::BJ => "Benin",
+            CountryCode::BM => "Bermuda",
+            CountryCode::BT => "Bhutan",
+            CountryCode::BO => "Bolivia",
+            CountryCode::BQ => "Bonaire, Sint Eustatius and Saba",
+            CountryCode::BA => "Bosnia and Herzegovina",
+            CountryCode::BW => "Botswana",
+            CountryCode::BV => "Bouvet Island",
+            CountryCode::BR => "Brazil",
+            CountryCode::IO => "British Indian Ocean Territory",
+            CountryCode::BN => "Brunei Darussalam",
+            CountryCode::BG => "Bulgaria",
+            CountryCode::BF => "Burkina Faso",
+            CountryCode::BI => "Burundi",
+            CountryCode::CV => "Cabo Verde",
+            CountryCode::KH => "Cambodia",
+            CountryCode::CM => "Cameroon",
+            CountryCode::CA => "Canada",
+            CountryCode::KY => "Cayman Islands",
+            CountryCode::CF => "Central African Republic",
+            CountryCode::TD => "Chad",
+            CountryCode::CL => "Chile",
+            CountryCode::CN => "China",
+            CountryCode::CX => "Christmas Island",
+            CountryCode::CC => "Cocos (Keeling) Islands",
+            CountryCode::CO => "Colombia",
+            CountryCode::KM => "Comoros",
+            CountryCode::CG => "Congo",
+            CountryCode::CD => "Congo, The Democratic Republic of the",
+            CountryCode::CK => "Cook Islands",
+            CountryCode::CR => "Costa Rica",
+            CountryCode::CI => "Côte d'Ivoire",
+            CountryCode::HR => "Croatia",
+            CountryCode::CU => "Cuba",
+            CountryCode::CW => "Curaçao",
+            CountryCode::CY => "Cyprus",
+            CountryCode::CZ => "Czechia",
+            CountryCode::DK => "Denmark",
+            CountryCode::DJ => "Djibouti",
+            CountryCode::DM => "Dominica",
+            CountryCode::DO => "Dominican Republic",
+            CountryCode::EC => "Ecuador",
+            CountryCode::EG => "Egypt",
+            CountryCode::SV => "El Salvador",
+            CountryCode::GQ => "Equatorial Guinea",
+            CountryCode::ER => "Eritrea",
+            CountryCode::EE => "Estonia",
+            CountryCode::ET => "Ethiopia",
+            CountryCode::FK => "Falkland Islands (Malvinas)",
+            CountryCode::FO => "Faroe Islands",
+            CountryCode::FJ => "Fiji",
+            CountryCode::FI => "Finland",
+            CountryCode::FR => "France",
+            CountryCode::GF => "French Guiana",
+            CountryCode::PF => "French Polynesia",
+            CountryCode::TF => "French Southern Territories",
+            CountryCode::GA => "Gabon",
+            CountryCode::GM => "Gambia",
+            CountryCode::GE => "Georgia",
+            CountryCode::DE => "Germany",
+            CountryCode::GH => "Ghana",
+            CountryCode::GI => "Gibraltar",
+            CountryCode::GR => "Greece",
+            CountryCode::GL => "Greenland",
+            CountryCode::GD => "Grenada",
+            CountryCode::GP => "Guadeloupe",
+            CountryCode::GU => "Guam",
+            CountryCode::GT => "Guatemala",
+            CountryCode::GG => "Guernsey",
+            CountryCode::GN => "Guinea",
+            CountryCode::GW => "Guinea-Bissau",
+            CountryCode::GY => "Guyana",
+            CountryCode::HT => "Haiti",
+            CountryCode::HM => "Heard Island and McDonald Islands",
+            CountryCode::VA => "Holy See (Vatican City State)",
+            CountryCode::HN => "Honduras",
+            CountryCode::HK => "Hong Kong",
+            CountryCode::HU => "Hungary",
+            CountryCode::IS => "Iceland",
+            CountryCode::IN => "India",
+            CountryCode::ID => "Indonesia",
+            CountryCode::IR => "Iran",
+            CountryCode::IQ => "Iraq",
+            CountryCode::IE => "Ireland",
+            CountryCode::IM => "Isle of Man",
+            CountryCode::IL => "Israel",
+            CountryCode::IT => "Italy",
+            CountryCode::JM => "Jamaica",
+            CountryCode::JP => "Japan",
+            CountryCode::JE => "Jersey",
+            CountryCode::JO => "Jordan",
+            CountryCode::KZ => "Kazakhstan",
+            CountryCode::KE => "Kenya",
+            CountryCode::KI => "Kiribati",
+            CountryCode::KP => "North Korea",
+            CountryCode::KR => "South Korea",
+            CountryCode::KW => "Kuwait",
+            CountryCode::KG => "Kyrgyzstan",
+            CountryCode::LA => "Laos",
+            CountryCode::LV => "Latvia",
+            CountryCode::LB => "Lebanon",
+            CountryCode::LS => "Lesotho",
+            CountryCode::LR => "Liberia",
+            CountryCode::LY => "Libya",
+            CountryCode::LI => "Liechtenstein",
+            CountryCode::LT => "Lithuania",
+            CountryCode::LU => "Luxembourg",
+            CountryCode::MO => "Macao",
+            CountryCode::MK => "North Macedonia",
+            CountryCode::MG => "Madagascar",
+            CountryCode::MW => "Malawi",
+            CountryCode::MY => "Malaysia",
+            CountryCode::MV => "Maldives",
+            CountryCode::ML => "Mali",
+            CountryCode::MT => "Malta",
+            CountryCode::MH => "Marshall Islands",
+            CountryCode::MQ => "Martinique",
+            CountryCode::MR => "Mauritania",
+            CountryCode::MU => "Mauritius",
+            CountryCode::YT => "Mayotte",
+            CountryCode::MX => "Mexico",
+            CountryCode::FM => "Micronesia, Federated States of",
+            CountryCode::MD => "Moldova",
+            CountryCode::MC => "Monaco",
+            CountryCode::MN => "Mongolia",
+            CountryCode::ME => "Montenegro",
+            CountryCode::MS => "Montserrat",
+            CountryCode::MA => "Morocco",
+            CountryCode::MZ => "Mozambique",
+            CountryCode::MM => "Myanmar",
+            CountryCode::NA => "Namibia",
+            CountryCode::NR => "Nauru",
+            CountryCode::NP => "Nepal",
+            CountryCode::NL => "Netherlands",
+            CountryCode::NC => "New Caledonia",
+            CountryCode::NZ => "New Zealand",
+            CountryCode::NI => "Nicaragua",
+            CountryCode::NE => "Niger",
+            CountryCode::NG => "Nigeria",
+            CountryCode::NU => "Niue",
+            CountryCode::NF => "Norfolk Island",
+            CountryCode::MP => "Northern Mariana Islands",
+            CountryCode::NO => "Norway",
+            CountryCode::OM => "Oman",
+            CountryCode::PK => "Pakistan",
+            CountryCode::PW => "Palau",
+            CountryCode::PS => "Palestine, State of",
+            CountryCode::PA => "Panama",
+            CountryCode::PG => "Papua New Guinea",
+            CountryCode::PY => "Paraguay",
+            CountryCode::PE => "Peru",
+            CountryCode::PH => "Philippines",
+            CountryCode::PN => "Pitcairn",
+            CountryCode::PL => "Poland",
+            CountryCode::PT => "Portugal",
+            CountryCode::PR => "Puerto Rico",
+            CountryCode::QA => "Qatar",
+            CountryCode::RE => "Réunion",
+            CountryCode::RO => "Romania",
+            CountryCode::RU => "Russian Federation",
+            CountryCode::RW => "Rwanda",
+            CountryCode::BL => "Saint Barthélemy",
+            CountryCode::SH => "Saint Helena, Ascension and Tristan da Cunha",
+            CountryCode::KN => "Saint Kitts and Nevis",
+            CountryCode::LC => "Saint Lucia",
+            CountryCode::MF => "Saint Martin (French part)",
+            CountryCode::PM => "Saint Pierre and Miquelon",
+            CountryCode::VC => "Saint Vincent and the Grenadines",
+            CountryCode::WS => "Samoa",
+            CountryCode::SM => "San Marino",
+            CountryCode::ST => "Sao Tome and Principe",
+            CountryCode::SA => "Saudi Arabia",
+            CountryCode::SN => "Senegal",
+            CountryCode::RS => "Serbia",
+            CountryCode::SC => "Seychelles",
+            CountryCode::SL => "Sierra Leone",
+            CountryCode::SG => "Singapore",
+            CountryCode::SX => "Sint Maarten (Dutch part)",
+            CountryCode::SK => "Slovakia",
+            CountryCode::SI => "Slovenia",
+            CountryCode::SB => "Solomon Islands",
+            CountryCode::SO => "Somalia",
+            CountryCode::ZA => "South Africa",
+            CountryCode::GS => "South Georgia and the South Sandwich Islands",
+            CountryCode::SS => "South Sudan",
+            CountryCode::ES => "Spain",
+            CountryCode::LK => "Sri Lanka",
+            CountryCode::SD => "Sudan",
+            CountryCode::SR => "Suriname",
+            CountryCode::SJ => "Svalbard and Jan Mayen",
+            CountryCode::SZ => "Eswatini",
+            CountryCode::SE => "Sweden",
+            CountryCode::CH => "Switzerland",
+            CountryCode::SY => "Syria",
+            CountryCode::TW => "Taiwan",
+            CountryCode::TJ => "Tajikistan",
+            CountryCode::TZ => "Tanzania",
+            CountryCode::TH => "Thailand",
+            CountryCode::TL => "Timor-Leste",
+            CountryCode::TG => "Togo",
+            CountryCode::TK => "Tokelau",
+            CountryCode::TO => "Tonga",
+            CountryCode::TT => "Trinidad and Tobago",
+            CountryCode::TN => "Tunisia",
+            CountryCode::TR => "Türkiye",
+            CountryCode::TM => "Turkmenistan",
+            CountryCode::TC => "Turks and Caicos Islands",
+            CountryCode::TV => "Tuvalu",
+            CountryCode::UG => "Uganda",
+            CountryCode::UA => "Ukraine",
+            CountryCode::AE => "United Arab Emirates",
+            CountryCode::GB => "United Kingdom",
+            CountryCode::US => "United States",
+            CountryCode::UM => "United States Minor Outlying Islands",
+            CountryCode::UY => "Uruguay",
+            CountryCode::UZ => "Uzbekistan",
+            CountryCode::VU => "Vanuatu",
+            CountryCode::VE => "Venezuela",
+            CountryCode::VN => "Vietnam",
+            CountryCode::VG => "Virgin Islands, British",
+            CountryCode::VI => "Virgin Islands, U.S.",
+            CountryCode::WF => "Wallis and Futuna",
+            CountryCode::EH => "Western Sahara",
+            CountryCode::YE => "Yemen",
+            CountryCode::ZM => "Zambia",
+            CountryCode::ZW => "Zimbabwe",
+            CountryCode::Unknown => "Unknown",
+        }
+    }
+
+    /// ISO 3166-1 三位字母代码，`Unknown` 对应用户自定义代码 `ZZZ`
+    pub fn alpha3(&self) -> &'static str {
+        match self {
+            CountryCode::AX => "ALA",
+            CountryCode::AL => "ALB",
+            CountryCode::AF => "AFG",
+            CountryCode::DZ => "DZA",
+            CountryCode::AS => "ASM",
+            CountryCode::AD => "AND",
+            CountryCode::AO => "AGO",
+            CountryCode::AI => "AIA",
+            CountryCode::AQ => "ATA",
+            CountryCode::AG => "ATG",
+            CountryCode::AR => "ARG",
+            CountryCode::AM => "ARM",
+            CountryCode::AW => "ABW",
+            CountryCode::AU => "AUS",
+            CountryCode::AT => "AUT",
+            CountryCode::AZ => "AZE",
+            CountryCode::BS => "BHS",
+            CountryCode::BH => "BHR",
+            CountryCode::BD => "BGD",
+            CountryCode::BB => "BRB",
+            CountryCode::BY => "BLR",
+            CountryCode::BE => "BEL",
+            CountryCode::BZ => "BLZ",
+            CountryCode::BJ => "BEN",
+            CountryCode::BM => "BMU",
+            CountryCode::BT => "BTN",
+            CountryCode::BO => "BOL",
+            CountryCode::BQ => "BES",
+            CountryCode::BA => "BIH",
+            CountryCode::BW => "BWA",
+            CountryCode::BV => "BVT",
+            CountryCode::BR => "BRA",
+            CountryCode::IO => "IOT",
+            CountryCode::BN => "BRN",
+            CountryCode::BG => "BGR",
+            CountryCode::BF => "BFA",
+            CountryCode::BI => "BDI",
+            CountryCode::CV => "CPV",
+            CountryCode::KH => "KHM",
+            CountryCode::CM => "CMR",
+            CountryCode::CA => "CAN",
+            CountryCode::KY => "CYM",
+            CountryCode::CF => "CAF",
+            CountryCode::TD => "TCD",
+            CountryCode::CL => "CHL",
+            CountryCode::CN => "CHN",
+            CountryCode::CX => "CXR",
+            CountryCode::CC => "CCK",
+            CountryCode::CO => "COL",
+            CountryCode::KM => "COM",
+            CountryCode::CG => "COG",
+            CountryCode::CD => "COD",
+            CountryCode::CK => "COK",
+            CountryCode::CR => "CRI",
+            CountryCode::CI => "CIV",
+            CountryCode::HR => "HRV",
+            CountryCode::CU => "CUB",
+            CountryCode::CW => "CUW",
+            CountryCode::CY => "CYP",
+            CountryCode::CZ => "CZE",
+            CountryCode::DK => "DNK",
+            CountryCode::DJ => "DJI",
+            CountryCode::DM => "DMA",
+            CountryCode::DO => "DOM",
+            CountryCode::EC => "ECU",
+            CountryCode::EG => "EGY",
+            CountryCode::SV => "SLV",
+            CountryCode::GQ => "GNQ",
+            CountryCode::ER => "ERI",
+            CountryCode::EE => "EST",
+            CountryCode::ET => "ETH",
+            CountryCode::FK => "FLK",
+            CountryCode::FO => "FRO",
+            CountryCode::FJ => "FJI",
+            CountryCode::FI => "FIN",
+            CountryCode::FR => "FRA",
+            CountryCode::GF => "GUF",
+            CountryCode::PF => "PYF",
+            CountryCode::TF => "ATF",
+            CountryCode::GA => "GAB",
+            CountryCode::GM => "GMB",
+            CountryCode::GE => "GEO",
+            CountryCode::DE => "DEU",
+            CountryCode::GH => "GHA",
+            CountryCode::GI => "GIB",
+            CountryCode::GR => "GRC",
+            CountryCode::GL => "GRL",
+            CountryCode::GD => "GRD",
+            CountryCode::GP => "GLP",
+            CountryCode::GU => "GUM",
+            CountryCode::GT => "GTM",
+            CountryCode::GG => "GGY",
+            CountryCode::GN => "GIN",
+            CountryCode::GW => "GNB",
+            CountryCode::GY => "GUY",
+            CountryCode::HT => "HTI",
+            CountryCode::HM => "HMD",
+            CountryCode::VA => "VAT",
+            CountryCode::HN => "HND",
+            CountryCode::HK => "HKG",
+            CountryCode::HU => "HUN",
+            CountryCode::IS => "ISL",
+            CountryCode::IN => "IND",
+            CountryCode::ID => "IDN",
+            CountryCode::IR => "IRN",
+            CountryCode::IQ => "IRQ",
+            CountryCode::IE => "IRL",
+            CountryCode::IM => "IMN",
+            CountryCode::IL => "ISR",
+            CountryCode::IT => "ITA",
+            CountryCode::JM => "JAM",
+            CountryCode::JP => "JPN",
+            CountryCode::JE => "JEY",
+            CountryCode::JO => "JOR",
+            CountryCode::KZ => "KAZ",
+            CountryCode::KE => "KEN",
+            CountryCode::KI => "KIR",
+            CountryCode::KP => "PRK",
+            CountryCode::KR => "KOR",
+            CountryCode::KW => "KWT",
+            CountryCode::KG => "KGZ",
+            CountryCode::LA => "LAO",
+            CountryCode::LV => "LVA",
+            CountryCode::LB => "LBN",
+            CountryCode::LS => "LSO",
+            CountryCode::LR => "LBR",
+            CountryCode::LY => "LBY",
+            CountryCode::LI => "LIE",
+            CountryCode::LT => "LTU",
+            CountryCode::LU => "LUX",
+            CountryCode::MO => "MAC",
+            CountryCode::MK => "MKD",
+            CountryCode::MG => "MDG",
+            CountryCode::MW => "MWI",
+            CountryCode::MY => "MYS",
+            CountryCode::MV => "MDV",
+            CountryCode::ML => "MLI",
+            CountryCode::MT => "MLT",
+            CountryCode::MH => "MHL",
+            CountryCode::MQ => "MTQ",
+            CountryCode::MR => "MRT",
+            CountryCode::MU => "MUS",
+            CountryCode::YT => "MYT",
+            CountryCode::MX => "MEX",
+            CountryCode::FM => "FSM",
+            CountryCode::MD => "MDA",
+            CountryCode::MC => "MCO",
+            CountryCode::MN => "MNG",
+            CountryCode::ME => "MNE",
+            CountryCode::MS => "MSR",
+            CountryCode::MA => "MAR",
+            CountryCode::MZ => "MOZ",
+            CountryCode::MM => "MMR",
+            CountryCode::NA => "NAM",
+            CountryCode::NR => "NRU",
+            CountryCode::NP => "NPL",
+            CountryCode::NL => "NLD",
+            CountryCode::NC => "NCL",
+            CountryCode::NZ => "NZL",
+            CountryCode::NI => "NIC",
+            CountryCode::NE => "NER",
+            CountryCode::NG => "NGA",
+            CountryCode::NU => "NIU",
+            CountryCode::NF => "NFK",
+            CountryCode::MP => "MNP",
+            CountryCode::NO => "NOR",
+            CountryCode::OM => "OMN",
+            CountryCode::PK => "PAK",
+            CountryCode::PW => "PLW",
+            CountryCode::PS => "PSE",
+            CountryCode::PA => "PAN",
+            CountryCode::PG => "PNG",
+            CountryCode::PY => "PRY",
+            CountryCode::PE => "PER",
+            CountryCode::PH => "PHL",
+            CountryCode::PN => "PCN",
+            CountryCode::PL => "POL",
+            CountryCode::PT => "PRT",
+            CountryCode::PR => "PRI",
+            CountryCode::QA => "QAT",
+            CountryCode::RE => "REU",
+            CountryCode::RO => "ROU",
+            CountryCode::RU => "RUS",
+            CountryCode::RW => "RWA",
+            CountryCode::BL => "BLM",
+            CountryCode::SH => "SHN",
+            CountryCode::KN => "KNA",
+            CountryCode::LC => "LCA",
+            CountryCode::MF => "MAF",
+            CountryCode::PM => "SPM",
+            CountryCode::VC => "VCT",
+            CountryCode::WS => "WSM",
+            CountryCode::SM => "SMR",
+            CountryCode::ST => "STP",
+            CountryCode::SA => "SAU",
+            CountryCode::SN => "SEN",
+            CountryCode::RS => "SRB",
+            CountryCode::SC => "SYC",
+            CountryCode::SL => "SLE",
+            CountryCode::SG => "SGP",
+            CountryCode::SX => "SXM",
+            CountryCode::SK => "SVK",
+            CountryCode::SI => "SVN",
+            CountryCode::SB => "SLB",
+            CountryCode::SO => "SOM",
+            CountryCode::ZA => "ZAF",
+            CountryCode::GS => "SGS",
+            CountryCode::SS => "SSD",
+            CountryCode::ES => "ESP",
+            CountryCode::LK => "LKA",
+            CountryCode::SD => "SDN",
+            CountryCode::SR => "SUR",
+            CountryCode::SJ => "SJM",
+            CountryCode::SZ => "SWZ",
+            CountryCode::SE => "SWE",
+            CountryCode::CH => "CHE",
+            CountryCode::SY => "SYR",
+            CountryCode::TW => "TWN",
+            CountryCode::TJ => "TJK",
+            CountryCode::TZ => "TZA",
+            CountryCode::TH => "THA",
+            CountryCode::TL => "TLS",
+            CountryCode::TG => "TGO",
+            CountryCode::TK => "TKL",
+            CountryCode::TO => "TON",
+            CountryCode::TT => "TTO",
+            CountryCode::TN => "TUN",
+            CountryCode::TR => "TUR",
+            CountryCode::TM => "TKM",
+            CountryCode::TC => "TCA",
+            CountryCode::TV => "TUV",
+            CountryCode::UG => "UGA",
+            CountryCode::UA => "UKR",
+            CountryCode::AE => "ARE",
+            CountryCode::GB => "GBR",
+            CountryCode::US => "USA",
+            CountryCode::UM => "UMI",
+            CountryCode::UY => "URY",
+            CountryCode::UZ => "UZB",
+            CountryCode::VU => "VUT",
+            CountryCode::VE => "VEN",
+            CountryCode::VN => "VNM",
+            CountryCode::VG => "VGB",
+            CountryCode::VI => "VIR",
+            CountryCode::WF => "WLF",
+            CountryCode::EH => "ESH",
+            CountryCode::YE => "YEM",
+            CountryCode::ZM => "ZMB",
+            CountryCode::ZW => "ZWE",
+            CountryCode::Unknown => "ZZZ",
+        }
+    }
+
+    /// ISO 3166-1 数字代码，`Unknown` 对应用户自定义代码 999
+    pub fn numeric(&self) -> u16 {
+        match self {
+            CountryCode::AX => 248,
+            CountryCode::AL => 8,
+            CountryCode::AF => 4,
+            CountryCode::DZ => 12,
+            CountryCode::AS => 16,
+            CountryCode::AD => 20,
+            CountryCode::AO => 24,
+            CountryCode::AI => 660,
+            CountryCode::AQ => 10,
+            CountryCode::AG => 28,
+            CountryCode::AR => 32,
+            CountryCode::AM => 51,
+            CountryCode::AW => 533,
+            CountryCode::AU => 36,
+            CountryCode::AT => 40,
+            CountryCode::AZ => 31,
+            CountryCode::BS => 44,
+            CountryCode::BH => 48,
+            CountryCode::BD => 50,
+            CountryCode::BB => 52,
+            CountryCode::BY => 112,
+            CountryCode::BE => 56,
+            CountryCode::BZ => 84,
+            CountryCode::BJ => 204,
+            CountryCode::BM => 60,
+            CountryCode::BT => 64,
+            CountryCode::BO => 68,
+            CountryCode::BQ => 535,
+            CountryCode::BA => 70,
+            CountryCode::BW => 72,
+            CountryCode::BV => 74,
+            CountryCode::BR => 76,
+            CountryCode::IO => 86,
+            CountryCode::BN => 96,
+            CountryCode::BG => 100,
+            CountryCode::BF => 854,
+            CountryCode::BI => 108,
+            CountryCode::CV => 132,
+            CountryCode::KH => 116,
+            CountryCode::CM => 120,
+            CountryCode::CA => 124,
+            CountryCode::KY => 136,
+            CountryCode::CF => 140,
+            CountryCode::TD => 148,
+            CountryCode::CL => 152,
+            CountryCode::CN => 156,
+            CountryCode::CX => 162,
+            CountryCode::CC => 166,
+            CountryCode::CO => 170,
+            CountryCode::KM => 174,
+            CountryCode::CG => 178,
+            CountryCode::CD => 180,
+            CountryCode::CK => 184,
+            CountryCode::CR => 188,
+            CountryCode::CI => 384,
+            CountryCode::HR => 191,
+            CountryCode::CU => 192,
+            CountryCode::CW => 531,
+            CountryCode::CY => 196,
+            CountryCode::CZ => 203,
+            CountryCode::DK => 208,
+            CountryCode::DJ => 262,
+            CountryCode::DM => 212,
+            CountryCode::DO => 214,
+            CountryCode::EC => 218,
+            CountryCode::EG => 818,
+            CountryCode::SV => 222,
+            CountryCode::GQ => 226,
+            CountryCode::ER => 232,
+            CountryCode::EE => 233,
+            CountryCode::ET => 231,
+            CountryCode::FK => 238,
+            CountryCode::FO => 234,
+            CountryCode::FJ => 242,
+            CountryCode::FI => 246,
+            CountryCode::FR => 250,
+            CountryCode::GF => 254,
+            CountryCode::PF => 258,
+            CountryCode::TF => 260,
+            CountryCode::GA => 266,
+            CountryCode::GM => 270,
+            CountryCode::GE => 268,
+            CountryCode::DE => 276,
+            CountryCode::GH => 288,
+            CountryCode::GI => 292,
+            CountryCode::GR => 300,
+            CountryCode::GL => 304,
+            CountryCode::GD => 308,
+            CountryCode::GP => 312,
+            CountryCode::GU => 316,
+            CountryCode::GT => 320,
+            CountryCode::GG => 831,
+            CountryCode::GN => 324,
+            CountryCode::GW => 624,
+            CountryCode::GY => 328,
+            CountryCode::HT => 332,
+            CountryCode::HM => 334,
+            CountryCode::VA => 336,
+            CountryCode::HN => 340,
+            CountryCode::HK => 344,
+            CountryCode::HU => 348,
+            CountryCode::IS => 352,
+            CountryCode::IN => 356,
+            CountryCode::ID => 360,
+            CountryCode::IR => 364,
+            CountryCode::IQ => 368,
+            CountryCode::IE => 372,
+            CountryCode::IM => 833,
+            CountryCode::IL => 376,
+            CountryCode::IT => 380,
+            CountryCode::JM => 388,
+            CountryCode::JP => 392,
+            CountryCode::JE => 832,
+            CountryCode::JO => 400,
+            CountryCode::KZ => 398,
+            CountryCode::KE => 404,
+            CountryCode::KI => 296,
+            CountryCode::KP => 408,
+            CountryCode::KR => 410,
+            CountryCode::KW => 414,
+            CountryCode::KG => 417,
+            CountryCode::LA => 418,
+            CountryCode::LV => 428,
+            CountryCode::LB => 422,
+            CountryCode::LS => 426,
+            CountryCode::LR => 430,
+            CountryCode::LY => 434,
+            CountryCode::LI => 438,
+            CountryCode::LT => 440,
+            CountryCode::LU => 442,
+            CountryCode::MO => 446,
+            CountryCode::MK => 807,
+            CountryCode::MG => 450,
+            CountryCode::MW => 454,
+            CountryCode::MY => 458,
+            CountryCode::MV => 462,
+            CountryCode::ML => 466,
+            CountryCode::MT => 470,
+            CountryCode::MH => 584,
+            CountryCode::MQ => 474,
+            CountryCode::MR => 478,
+            CountryCode::MU => 480,
+            CountryCode::YT => 175,
+            CountryCode::MX => 484,
+            CountryCode::FM => 583,
+            CountryCode::MD => 498,
+            CountryCode::MC => 492,
+            CountryCode::MN => 496,
+            CountryCode::ME => 499,
+            CountryCode::MS => 500,
+            CountryCode::MA => 504,
+            CountryCode::MZ => 508,
+            CountryCode::MM => 104,
+            CountryCode::NA => 516,
+            CountryCode::NR => 520,
+            CountryCode::NP => 524,
+            CountryCode::NL => 528,
+            CountryCode::NC => 540,
+            CountryCode::NZ => 554,
+            CountryCode::NI => 558,
+            CountryCode::NE => 562,
+            CountryCode::NG => 566,
+            CountryCode::NU => 570,
+            CountryCode::NF => 574,
+            CountryCode::MP => 580,
+            CountryCode::NO => 578,
+            CountryCode::OM => 512,
+            CountryCode::PK => 586,
+            CountryCode::PW => 585,
+            CountryCode::PS => 275,
+            CountryCode::PA => 591,
+            CountryCode::PG => 598,
+            CountryCode::PY => 600,
+            CountryCode::PE => 604,
+            CountryCode::PH => 608,
+            CountryCode::PN => 612,
+            CountryCode::PL => 616,
+            CountryCode::PT => 620,
+            CountryCode::PR => 630,
+            CountryCode::QA => 634,
+            CountryCode::RE => 638,
+            CountryCode::RO => 642,
+            CountryCode::RU => 643,
+            CountryCode::RW => 646,
+            CountryCode::BL => 652,
+            CountryCode::SH => 654,
+            CountryCode::KN => 659,
+            CountryCode::LC => 662,
+            CountryCode::MF => 663,
+            CountryCode::PM => 666,
+            CountryCode::VC => 670,
+            CountryCode::WS => 882,
+            CountryCode::SM => 674,
+            CountryCode::ST => 678,
+            CountryCode::SA => 682,
+            CountryCode::SN => 686,
+            CountryCode::RS => 688,
+            CountryCode::SC => 690,
+            CountryCode::SL => 694,
+            CountryCode::SG => 702,
+            CountryCode::SX => 534,
+            CountryCode::SK => 703,
+            CountryCode::SI => 705,
+            CountryCode::SB => 90,
+            CountryCode::SO => 706,
+            CountryCode::ZA => 710,
+            CountryCode::GS => 239,
+            CountryCode::SS => 728,
+            CountryCode::ES => 724,
+            CountryCode::LK => 144,
+            CountryCode::SD => 729,
+            CountryCode::SR => 740,
+            CountryCode::SJ => 744,
+            CountryCode::SZ => 748,
+            CountryCode::SE => 752,
+            CountryCode::CH => 756,
+            CountryCode::SY => 760,
+            CountryCode::TW => 158,
+            CountryCode::TJ => 762,
+            CountryCode::TZ => 834,
+            CountryCode::TH => 764,
+            CountryCode::TL => 626,
+            CountryCode::TG => 768,
+            CountryCode::TK => 772,
+            CountryCode::TO => 776,
+            CountryCode::TT => 780,
+            CountryCode::TN => 788,
+            CountryCode::TR => 792,
+            CountryCode::TM => 795,
+            CountryCode::TC => 796,
+            CountryCode::TV => 798,
+            CountryCode::UG => 800,
+            CountryCode::UA => 804,
+            CountryCode::AE => 784,
+            CountryCode::GB => 826,
+            CountryCode::US => 840,
+            CountryCode::UM => 581,
+            CountryCode::UY => 858,
+            CountryCode::UZ => 860,
+            CountryCode::VU => 548,
+            CountryCode::VE => 862,
+            CountryCode::VN => 704,
+            CountryCode::VG => 92,
+            CountryCode::VI => 850,
+            CountryCode::WF => 876,
+            CountryCode::EH => 732,
+            CountryCode::YE => 887,
+            CountryCode::ZM => 894,
+            CountryCode::ZW => 716,
+            CountryCode::Unknown => 999,
+        }
+    }
+
+    /// 国际电话区号，例如 `+86`；无人居住的地区没有区号
+    pub fn calling_code(&self) -> Option<&'static str> {
+        match self {
+            CountryCode::AX => Some("+358"),
+            CountryCode::AL => Some("+355"),
+            CountryCode::AF => Some("+93"),
+            CountryCode::DZ => Some("+213"),
+            CountryCode::AS => Some("+1684"),
+            CountryCode::AD => Some("+376"),
+            CountryCode::AO => Some("+244"),
+            CountryCode::AI => Some("+1264"),
+            CountryCode::AQ => None,
+            CountryCode::AG => Some("+1268"),
+            CountryCode::AR => Some("+54"),
+            CountryCode::AM => Some("+374"),
+            CountryCode::AW => Some("+297"),
+            CountryCode::AU => Some("+61"),
+            CountryCode::AT => Some("+43"),
+            CountryCode::AZ => Some("+994"),
+            CountryCode::BS => Some("+1242"),
+            CountryCode::BH => Some("+973"),
+            CountryCode::BD => Some("+880"),
+            CountryCode::BB => Some("+1246"),
+            CountryCode::BY => Some("+375"),
+            CountryCode::BE => Some("+32"),
+            CountryCode::BZ => Some("+501"),
+            CountryCode::BJ => Some("+229"),
+            CountryCode::BM => Some("+1441"),
+            CountryCode::BT => Some("+975"),
+            CountryCode::BO => Some("+591"),
+            CountryCode::BQ => Some("+599"),
+            CountryCode::BA => Some("+387"),
+            CountryCode::BW => Some("+267"),
+            CountryCode::BV => None,
+            CountryCode::BR => Some("+55"),
+            CountryCode::IO => Some("+246"),
+            CountryCode::BN => Some("+673"),
+            CountryCode::BG => Some("+359"),
+            CountryCode::BF => Some("+226"),
+            CountryCode::BI => Some("+257"),
+            CountryCode::CV => Some("+238"),
+            CountryCode::KH => Some("+855"),
+            CountryCode::CM => Some("+237"),
+            CountryCode::CA => Some("+1"),
+            CountryCode::KY => Some("+1345"),
+            CountryCode::CF => Some("+236"),
+            CountryCode::TD => Some("+235"),
+            CountryCode::CL => Some("+56"),
+            CountryCode::CN => Some("+86"),
+            CountryCode::CX => Some("+61"),
+            CountryCode::CC => Some("+61"),
+            CountryCode::CO => Some("+57"),
+            CountryCode::KM => Some("+269"),
+            CountryCode::CG => Some("+242"),
+            CountryCode::CD => Some("+243"),
+            CountryCode::CK => Some("+682"),
+            CountryCode::CR => Some("+506"),
+            CountryCode::CI => Some("+225"),
+            CountryCode::HR => Some("+385"),
+            CountryCode::CU => Some("+53"),
+            CountryCode::CW => Some("+599"),
+            CountryCode::CY => Some("+357"),
+            CountryCode::CZ => Some("+420"),
+            CountryCode::DK => Some("+45"),
+            CountryCode::DJ => Some("+253"),
+            CountryCode::DM => Some("+1767"),
+            CountryCode::DO => Some("+1809"),
+            CountryCode::EC => Some("+593"),
+            CountryCode::EG => Some("+20"),
+            CountryCode::SV => Some("+503"),
+            CountryCode::GQ => Some("+240"),
+            CountryCode::ER => Some("+291"),
+            CountryCode::EE => Some("+372"),
+            CountryCode::ET => Some("+251"),
+            CountryCode::FK => Some("+500"),
+            CountryCode::FO => Some("+298"),
+            CountryCode::FJ => Some("+679"),
+            CountryCode::FI => Some("+358"),
+            CountryCode::FR => Some("+33"),
+            CountryCode::GF => Some("+594"),
+            CountryCode::PF => Some("+689"),
+            CountryCode::TF => Some("+262"),
+            CountryCode::GA => Some("+241"),
+            CountryCode::GM => Some("+220"),
+            CountryCode::GE => Some("+995"),
+            CountryCode::DE => Some("+49"),
+            CountryCode::GH => Some("+233"),
+            CountryCode::GI => Some("+350"),
+            CountryCode::GR => Some("+30"),
+            CountryCode::GL => Some("+299"),
+            CountryCode::GD => Some("+1473"),
+            CountryCode::GP => Some("+590"),
+            CountryCode::GU => Some("+1671"),
+            CountryCode::GT => Some("+502"),
+            CountryCode::GG => Some("+44"),
+            CountryCode::GN => Some("+224"),
+            CountryCode::GW => Some("+245"),
+            CountryCode::GY => Some("+592"),
+            CountryCode::HT => Some("+509"),
+            CountryCode::HM => None,
+            CountryCode::VA => Some("+39"),
+            CountryCode::HN => Some("+504"),
+            CountryCode::HK => Some("+852"),
+            CountryCode::HU => Some("+36"),
+            CountryCode::IS => Some("+354"),
+            CountryCode::IN => Some("+91"),
+            CountryCode::ID => Some("+62"),
+            CountryCode::IR => Some("+98"),
+            CountryCode::IQ => Some("+964"),
+            CountryCode::IE => Some("+353"),
+            CountryCode::IM => Some("+44"),
+            CountryCode::IL => Some("+972"),
+            CountryCode::IT => Some("+39"),
+            CountryCode::JM => Some("+1876"),
+            CountryCode::JP => Some("+81"),
+            CountryCode::JE => Some("+44"),
+            CountryCode::JO => Some("+962"),
+            CountryCode::KZ => Some("+7"),
+            CountryCode::KE => Some("+254"),
+            CountryCode::KI => Some("+686"),
+            CountryCode::KP => Some("+850"),
+            CountryCode::KR => Some("+82"),
+            CountryCode::KW => Some("+965"),
+            CountryCode::KG => Some("+996"),
+            CountryCode::LA => Some("+856"),
+            CountryCode::LV => Some("+371"),
+            CountryCode::LB => Some("+961"),
+            CountryCode::LS => Some("+266"),
+            CountryCode::LR => Some("+231"),
+            CountryCode::LY => Some("+218"),
+            CountryCode::LI => Some("+423"),
+            CountryCode::LT => Some("+370"),
+            CountryCode::LU => Some("+352"),
+            CountryCode::MO => Some("+853"),
+            CountryCode::MK => Some("+389"),
+            CountryCode::MG => Some("+261"),
+            CountryCode::MW => Some("+265"),
+            CountryCode::MY => Some("+60"),
+            CountryCode::MV => Some("+960"),
+            CountryCode::ML => Some("+223"),
+            CountryCode::MT => Some("+356"),
+            CountryCode::MH => Some("+692"),
+            CountryCode::MQ => Some("+596"),
+            CountryCode::MR => Some("+222"),
+            CountryCode::MU => Some("+230"),
+            CountryCode::YT => Some("+262"),
+            CountryCode::MX => Some("+52"),
+            CountryCode::FM => Some("+691"),
+            CountryCode::MD => Some("+373"),
+            CountryCode::MC => Some("+377"),
+            CountryCode::MN => Some("+976"),
+            CountryCode::ME => Some("+382"),
+            CountryCode::MS => Some("+1664"),
+            CountryCode::MA => Some("+212"),
+            CountryCode::MZ => Some("+258"),
+            CountryCode::MM => Some("+95"),
+            CountryCode::NA => Some("+264"),
+            CountryCode::NR => Some("+674"),
+            CountryCode::NP => Some("+977"),
+            CountryCode::NL => Some("+31"),
+            CountryCode::NC => Some("+687"),
+            CountryCode::NZ => Some("+64"),
+            CountryCode::NI => Some("+505"),
+            CountryCode::NE => Some("+227"),
+            CountryCode::NG => Some("+234"),
+            CountryCode::NU => Some("+683"),
+            CountryCode::NF => Some("+672"),
+            CountryCode::MP => Some("+1670"),
+            CountryCode::NO => Some("+47"),
+            CountryCode::OM => Some("+968"),
+            CountryCode::PK => Some("+92"),
+            CountryCode::PW => Some("+680"),
+            CountryCode::PS => Some("+970"),
+            CountryCode::PA => Some("+507"),
+            CountryCode::PG => Some("+675"),
+            CountryCode::PY => Some("+595"),
+            CountryCode::PE => Some("+51"),
+            CountryCode::PH => Some("+63"),
+            CountryCode::PN => Some("+64"),
+            CountryCode::PL => Some("+48"),
+            CountryCode::PT => Some("+351"),
+            CountryCode::PR => Some("+1787"),
+            CountryCode::QA => Some("+974"),
+            CountryCode::RE => Some("+262"),
+            CountryCode::RO => Some("+40"),
+            CountryCode::RU => Some("+7"),
+            CountryCode::RW => Some("+250"),
+            CountryCode::BL => Some("+590"),
+            CountryCode::SH => Some("+290"),
+            CountryCode::KN => Some("+1869"),
+            CountryCode::LC => Some("+1758"),
+            CountryCode::MF => Some("+590"),
+            CountryCode::PM => Some("+508"),
+            CountryCode::VC => Some("+1784"),
+            CountryCode::WS => Some("+685"),
+            CountryCode::SM => Some("+378"),
+            CountryCode::ST => Some("+239"),
+            CountryCode::SA => Some("+966"),
+            CountryCode::SN => Some("+221"),
+            CountryCode::RS => Some("+381"),
+            CountryCode::SC => Some("+248"),
+            CountryCode::SL => Some("+232"),
+            CountryCode::SG => Some("+65"),
+            CountryCode::SX => Some("+1721"),
+            CountryCode::SK => Some("+421"),
+            CountryCode::SI => Some("+386"),
+            CountryCode::SB => Some("+677"),
+            CountryCode::SO => Some("+252"),
+            CountryCode::ZA => Some("+27"),
+            CountryCode::GS => Some("+500"),
+            CountryCode::SS => Some("+211"),
+            CountryCode::ES => Some("+34"),
+            CountryCode::LK => Some("+94"),
+            CountryCode::SD => Some("+249"),
+            CountryCode::SR => Some("+597"),
+            CountryCode::SJ => Some("+47"),
+            CountryCode::SZ => Some("+268"),
+            CountryCode::SE => Some("+46"),
+            CountryCode::CH => Some("+41"),
+            CountryCode::SY => Some("+963"),
+            CountryCode::TW => Some("+886"),
+            CountryCode::TJ => Some("+992"),
+            CountryCode::TZ => Some("+255"),
+            CountryCode::TH => Some("+66"),
+            CountryCode::TL => Some("+670"),
+            CountryCode::TG => Some("+228"),
+            CountryCode::TK => Some("+690"),
+            CountryCode::TO => Some("+676"),
+            CountryCode::TT => Some("+1868"),
+            CountryCode::TN => Some("+216"),
+            CountryCode::TR => Some("+90"),
+            CountryCode::TM => Some("+993"),
+            CountryCode::TC => Some("+1649"),
+            CountryCode::TV => Some("+688"),
+            CountryCode::UG => Some("+256"),
+            CountryCode::UA => Some("+380"),
+            CountryCode::AE => Some("+971"),
+            CountryCode::GB => Some("+44"),
+            CountryCode::US => Some("+1"),
+            CountryCode::UM => Some("+1"),
+            CountryCode::UY => Some("+598"),
+            CountryCode::UZ => Some("+998"),
+            CountryCode::VU => Some("+678"),
+            CountryCode::VE => Some("+58"),
+            CountryCode::VN => Some("+84"),
+            CountryCode::VG => Some("+1284"),
+            CountryCode::VI => Some("+1340"),
+            CountryCode::WF => Some("+681"),
+            CountryCode::EH => Some("+212"),
+            CountryCode::YE => Some("+967"),
+            CountryCode::ZM => Some("+260"),
+            CountryCode::ZW => Some("+263"),
+            CountryCode::Unknown => None,
+        }
+    }
+
+    /// 通用的 ISO 4217 货币代码
+    pub fn currency(&self) -> Option<&'static str> {
+        match self {
+            CountryCode::AX => Some("EUR"),
+            CountryCode::AL => Some("ALL"),
+            CountryCode::AF => Some("AFN"),
+            CountryCode::DZ => Some("DZD"),
+            CountryCode::AS => Some("USD"),
+            CountryCode::AD => Some("EUR"),
+            CountryCode::AO => Some("AOA"),
+            CountryCode::AI => Some("XCD"),
+            CountryCode::AQ => None,
+            CountryCode::AG => Some("XCD"),
+            CountryCode::AR => Some("ARS"),
+            CountryCode::AM => Some("AMD"),
+            CountryCode::AW => Some("AWG"),
+            CountryCode::AU => Some("AUD"),
+            CountryCode::AT => Some("EUR"),
+            CountryCode::AZ => Some("AZN"),
+            CountryCode::BS => Some("BSD"),
+            CountryCode::BH => Some("BHD"),
+            CountryCode::BD => Some("BDT"),
+            CountryCode::BB => Some("BBD"),
+            CountryCode::BY => Some("BYN"),
+            CountryCode::BE => Some("EUR"),
+            CountryCode::BZ => Some("BZD"),
+            CountryCode::BJ => Some("XOF"),
+            CountryCode::BM => Some("BMD"),
+            CountryCode::BT => Some("BTN"),
+            CountryCode::BO => Some("BOB"),
+            CountryCode::BQ => Some("USD"),
+            CountryCode::BA => Some("BAM"),
+            CountryCode::BW => Some("BWP"),
+            CountryCode::BV => Some("NOK"),
+            CountryCode::BR => Some("BRL"),
+            CountryCode::IO => Some("USD"),
+            CountryCode::BN => Some("BND"),
+            CountryCode::BG => Some("BGN"),
+            CountryCode::BF => Some("XOF"),
+            CountryCode::BI => Some("BIF"),
+            CountryCode::CV => Some("CVE"),
+            CountryCode::KH => Some("KHR"),
+            CountryCode::CM => Some("XAF"),
+            CountryCode::CA => Some("CAD"),
+            CountryCode::KY => Some("KYD"),
+            CountryCode::CF => Some("XAF"),
+            CountryCode::TD => Some("XAF"),
+            CountryCode::CL => Some("CLP"),
+            CountryCode::CN => Some("CNY"),
+            CountryCode::CX => Some("AUD"),
+            CountryCode::CC => Some("AUD"),
+            CountryCode::CO => Some("COP"),
+            CountryCode::KM => Some("KMF"),
+            CountryCode::CG => Some("XAF"),
+            CountryCode::CD => Some("CDF"),
+            CountryCode::CK => Some("NZD"),
+            CountryCode::CR => Some("CRC"),
+            CountryCode::CI => Some("XOF"),
+            CountryCode::HR => Some("EUR"),
+            CountryCode::CU => Some("CUP"),
+            CountryCode::CW => Some("XCG"),
+            CountryCode::CY => Some("EUR"),
+            CountryCode::CZ => Some("CZK"),
+            CountryCode::DK => Some("DKK"),
+            CountryCode::DJ => Some("DJF"),
+            CountryCode::DM => Some("XCD"),
+            CountryCode::DO => Some("DOP"),
+            CountryCode::EC => Some("USD"),
+            CountryCode::EG => Some("EGP"),
+            CountryCode::SV => Some("USD"),
+            CountryCode::GQ => Some("XAF"),
+            CountryCode::ER => Some("ERN"),
+            CountryCode::EE => Some("EUR"),
+            CountryCode::ET => Some("ETB"),
+            CountryCode::FK => Some("FKP"),
+            CountryCode::FO => Some("DKK"),
+            CountryCode::FJ => Some("FJD"),
+            CountryCode::FI => Some("EUR"),
+            CountryCode::FR => Some("EUR"),
+            CountryCode::GF => Some("EUR"),
+            CountryCode::PF => Some("XPF"),
+            CountryCode::TF => Some("EUR"),
+            CountryCode::GA => Some("XAF"),
+            CountryCode::GM => Some("GMD"),
+            CountryCode::GE => Some("GEL"),
+            CountryCode::DE => Some("EUR"),
+            CountryCode::GH => Some("GHS"),
+            CountryCode::GI => Some("GIP"),
+            CountryCode::GR => Some("EUR"),
+            CountryCode::GL => Some("DKK"),
+            CountryCode::GD => Some("XCD"),
+            CountryCode::GP => Some("EUR"),
+            CountryCode::GU => Some("USD"),
+            CountryCode::GT => Some("GTQ"),
+            CountryCode::GG => Some("GBP"),
+            CountryCode::GN => Some("GNF"),
+            CountryCode::GW => Some("XOF"),
+            CountryCode::GY => Some("GYD"),
+            CountryCode::HT => Some("HTG"),
+            CountryCode::HM => Some("AUD"),
+            CountryCode::VA => Some("EUR"),
+            CountryCode::HN => Some("HNL"),
+            CountryCode::HK => Some("HKD"),
+            CountryCode::HU => Some("HUF"),
+            CountryCode::IS => Some("ISK"),
+            CountryCode::IN => Some("INR"),
+            CountryCode::ID => Some("IDR"),
+            CountryCode::IR => Some("IRR"),
+            CountryCode::IQ => Some("IQD"),
+            CountryCode::IE => Some("EUR"),
+            CountryCode::IM => Some("GBP"),
+            CountryCode::IL => Some("ILS"),
+            CountryCode::IT => Some("EUR"),
+            CountryCode::JM => Some("JMD"),
+            CountryCode::JP => Some("JPY"),
+            CountryCode::JE => Some("GBP"),
+            CountryCode::JO => Some("JOD"),
+            CountryCode::KZ => Some("KZT"),
+            CountryCode::KE => Some("KES"),
+            CountryCode::KI => Some("AUD"),
+            CountryCode::KP => Some("KPW"),
+            CountryCode::KR => Some("KRW"),
+            CountryCode::KW => Some("KWD"),
+            CountryCode::KG => Some("KGS"),
+            CountryCode::LA => Some("LAK"),
+            CountryCode::LV => Some("EUR"),
+            CountryCode::LB => Some("LBP"),
+            CountryCode::LS => Some("LSL"),
+            CountryCode::LR => Some("LRD"),
+            CountryCode::LY => Some("LYD"),
+            CountryCode::LI => Some("CHF"),
+            CountryCode::LT => Some("EUR"),
+            CountryCode::LU => Some("EUR"),
+            CountryCode::MO => Some("MOP"),
+            CountryCode::MK => Some("MKD"),
+            CountryCode::MG => Some("MGA"),
+            CountryCode::MW => Some("MWK"),
+            CountryCode::MY => Some("MYR"),
+            CountryCode::MV => Some("MVR"),
+            CountryCode::ML => Some("XOF"),
+            CountryCode::MT => Some("EUR"),
+            CountryCode::MH => Some("USD"),
+            CountryCode::MQ => Some("EUR"),
+            CountryCode::MR => Some("MRU"),
+            CountryCode::MU => Some("MUR"),
+            CountryCode::YT => Some("EUR"),
+            CountryCode::MX => Some("MXN"),
+            CountryCode::FM => Some("USD"),
+            CountryCode::MD => Some("MDL"),
+            CountryCode::MC => Some("EUR"),
+            CountryCode::MN => Some("MNT"),
+            CountryCode::ME => Some("EUR"),
+            CountryCode::MS => Some("XCD"),
+            CountryCode::MA => Some("MAD"),
+            CountryCode::MZ => Some("MZN"),
+            CountryCode::MM => Some("MMK"),
+            CountryCode::NA => Some("NAD"),
+            CountryCode::NR => Some("AUD"),
+            CountryCode::NP => Some("NPR"),
+            CountryCode::NL => Some("EUR"),
+            CountryCode::NC => Some("XPF"),
+            CountryCode::NZ => Some("NZD"),
+            CountryCode::NI => Some("NIO"),
+            CountryCode::NE => Some("XOF"),
+            CountryCode::NG => Some("NGN"),
+            CountryCode::NU => Some("NZD"),
+            CountryCode::NF => Some("AUD"),
+            CountryCode::MP => Some("USD"),
+            CountryCode::NO => Some("NOK"),
+            CountryCode::OM => Some("OMR"),
+            CountryCode::PK => Some("PKR"),
+            CountryCode::PW => Some("USD"),
+            CountryCode::PS => Some("ILS"),
+            CountryCode::PA => Some("PAB"),
+            CountryCode::PG => Some("PGK"),
+            CountryCode::PY => Some("PYG"),
+            CountryCode::PE => Some("PEN"),
+            CountryCode::PH => Some("PHP"),
+            CountryCode::PN => Some("NZD"),
+            CountryCode::PL => Some("PLN"),
+            CountryCode::PT => Some("EUR"),
+            CountryCode::PR => Some("USD"),
+            CountryCode::QA => Some("QAR"),
+            CountryCode::RE => Some("EUR"),
+            CountryCode::RO => Some("RON"),
+            CountryCode::RU => Some("RUB"),
+            CountryCode::RW => Some("RWF"),
+            CountryCode::BL => Some("EUR"),
+            CountryCode::SH => Some("SHP"),
+            CountryCode::KN => Some("XCD"),
+            CountryCode::LC => Some("XCD"),
+            CountryCode::MF => Some("EUR"),
+            CountryCode::PM => Some("EUR"),
+            CountryCode::VC => Some("XCD"),
+            CountryCode::WS => Some("WST"),
+            CountryCode::SM => Some("EUR"),
+            CountryCode::ST => Some("STN"),
+            CountryCode::SA => Some("SAR"),
+            CountryCode::SN => Some("XOF"),
+            CountryCode::RS => Some("RSD"),
+            CountryCode::SC => Some("SCR"),
+            CountryCode::SL => Some("SLE"),
+            CountryCode::SG => Some("SGD"),
+            CountryCode::SX => Some("XCG"),
+            CountryCode::SK => Some("EUR"),
+            CountryCode::SI => Some("EUR"),
+            CountryCode::SB => Some("SBD"),
+            CountryCode::SO => Some("SOS"),
+            CountryCode::ZA => Some("ZAR"),
+            CountryCode::GS => Some("GBP"),
+            CountryCode::SS => Some("SSP"),
+            CountryCode::ES => Some("EUR"),
+            CountryCode::LK => Some("LKR"),
+            CountryCode::SD => Some("SDG"),
+            CountryCode::SR => Some("SRD"),
+            CountryCode::SJ => Some("NOK"),
+            CountryCode::SZ => Some("SZL"),
+            CountryCode::SE => Some("SEK"),
+            CountryCode::CH => Some("CHF"),
+            CountryCode::SY => Some("SYP"),
+            CountryCode::TW => Some("TWD"),
+            CountryCode::TJ => Some("TJS"),
+            CountryCode::TZ => Some("TZS"),
+            CountryCode::TH => Some("THB"),
+            CountryCode::TL => Some("USD"),
+            CountryCode::TG => Some("XOF"),
+            CountryCode::TK => Some("NZD"),
+            CountryCode::TO => Some("TOP"),
+            CountryCode::TT => Some("TTD"),
+            CountryCode::TN => Some("TND"),
+            CountryCode::TR => Some("TRY"),
+            CountryCode::TM => Some("TMT"),
+            CountryCode::TC => Some("USD"),
+            CountryCode::TV => Some("AUD"),
+            CountryCode::UG => Some("UGX"),
+            CountryCode::UA => Some("UAH"),
+            CountryCode::AE => Some("AED"),
+            CountryCode::GB => Some("GBP"),
+            CountryCode::US => Some("USD"),
+            CountryCode::UM => Some("USD"),
+            CountryCode::UY => Some("UYU"),
+            CountryCode::UZ => Some("UZS"),
+            CountryCode::VU => Some("VUV"),
+            CountryCode::VE => Some("VES"),
+            CountryCode::VN => Some("VND"),
+            CountryCode::VG => Some("USD"),
+            CountryCode::VI => Some("USD"),
+            CountryCode::WF => Some("XPF"),
+            CountryCode::EH => Some("MAD"),
+            CountryCode::YE => Some("YER"),
+            CountryCode::ZM => Some("ZMW"),
+            CountryCode::ZW => Some("ZWL"),
+            CountryCode::Unknown => None,
+        }
+    }
+
+    /// 所在的大洲
+    pub fn continent(&self) -> Option<Continent> {
+        match self {
+            CountryCode::AX => Some(Continent::Europe),
+            CountryCode::AL => Some(Continent::Europe),
+            CountryCode::AF => Some(Continent::Asia),
+            CountryCode::DZ => Some(Continent::Africa),
+            CountryCode::AS => Some(Continent::Oceania),
+            CountryCode::AD => Some(Continent::Europe),
+            CountryCode::AO => Some(Continent::Africa),
+            CountryCode::AI => Some(Continent::NorthAmerica),
+            CountryCode::AQ => Some(Continent::Antarctica),
+            CountryCode::AG => Some(Continent::NorthAmerica),
+            CountryCode::AR => Some(Continent::SouthAmerica),
+            CountryCode::AM => Some(Continent::Asia),
+            CountryCode::AW => Some(Continent::NorthAmerica),
+            CountryCode::AU => Some(Continent::Oceania),
+            CountryCode::AT => Some(Continent::Europe),
+            CountryCode::AZ => Some(Continent::Asia),
+            CountryCode::BS => Some(Continent::NorthAmerica),
+            CountryCode::BH => Some(Continent::Asia),
+            CountryCode::BD => Some(Continent::Asia),
+            CountryCode::BB => Some(Continent::NorthAmerica),
+            CountryCode::BY => Some(Continent::Europe),
+            CountryCode::BE => Some(Continent::Europe),
+            CountryCode::BZ => Some(Continent::NorthAmerica),
+            CountryCode::BJ => Some(Continent::Africa),
+            CountryCode::BM => Some(Continent::NorthAmerica),
+            CountryCode::BT => Some(Continent::Asia),
+            CountryCode::BO => Some(Continent::SouthAmerica),
+            CountryCode::BQ => Some(Continent::NorthAmerica),
+            CountryCode::BA => Some(Continent::Europe),
+            CountryCode::BW => Some(Continent::Africa),
+            CountryCode::BV => Some(Continent::Antarctica),
+            CountryCode::BR => Some(Continent::SouthAmerica),
+            CountryCode::IO => Some(Continent::Asia),
+            CountryCode::BN => Some(Continent::Asia),
+            CountryCode::BG => Some(Continent::Europe),
+            CountryCode::BF => Some(Continent::Africa),
+            CountryCode::BI => Some(Continent::Africa),
+            CountryCode::CV => Some(Continent::Africa),
+            CountryCode::KH => Some(Continent::Asia),
+            CountryCode::CM => Some(Continent::Africa),
+            CountryCode::CA => Some(Continent::NorthAmerica),
+            CountryCode::KY => Some(Continent::NorthAmerica),
+            CountryCode::CF => Some(Continent::Africa),
+            CountryCode::TD => Some(Continent::Africa),
+            CountryCode::CL => Some(Continent::SouthAmerica),
+            CountryCode::CN => Some(Continent::Asia),
+            CountryCode::CX => Some(Continent::Asia),
+            CountryCode::CC => Some(Continent::Asia),
+            CountryCode::CO => Some(Continent::SouthAmerica),
+            CountryCode::KM => Some(Continent::Africa),
+            CountryCode::CG => Some(Continent::Africa),
+            CountryCode::CD => Some(Continent::Africa),
+            CountryCode::CK => Some(Continent::Oceania),
+            CountryCode::CR => Some(Continent::NorthAmerica),
+            CountryCode::CI => Some(Continent::Africa),
+            CountryCode::HR => Some(Continent::Europe),
+            CountryCode::CU => Some(Continent::NorthAmerica),
+            CountryCode::CW => Some(Continent::NorthAmerica),
+            CountryCode::CY => Some(Continent::Europe),
+            CountryCode::CZ => Some(Continent::Europe),
+            CountryCode::DK => Some(Continent::Europe),
+            CountryCode::DJ => Some(Continent::Africa),
+            CountryCode::DM => Some(Continent::NorthAmerica),
+            CountryCode::DO => Some(Continent::NorthAmerica),
+            CountryCode::EC => Some(Continent::SouthAmerica),
+            CountryCode::EG => Some(Continent::Africa),
+            CountryCode::SV => Some(Continent::NorthAmerica),
+            CountryCode::GQ => Some(Continent::Africa),
+            CountryCode::ER => Some(Continent::Africa),
+            CountryCode::EE => Some(Continent::Europe),
+            CountryCode::ET => Some(Continent::Africa),
+            CountryCode::FK => Some(Continent::SouthAmerica),
+            CountryCode::FO => Some(Continent::Europe),
+            CountryCode::FJ => Some(Continent::Oceania),
+            CountryCode::FI => Some(Continent::Europe),
+            CountryCode::FR => Some(Continent::Europe),
+            CountryCode::GF => Some(Continent::SouthAmerica),
+            CountryCode::PF => Some(Continent::Oceania),
+            CountryCode::TF => Some(Continent::Antarctica),
+            CountryCode::GA => Some(Continent::Africa),
+            CountryCode::GM => Some(Continent::Africa),
+            CountryCode::GE => Some(Continent::Asia),
+            CountryCode::DE => Some(Continent::Europe),
+            CountryCode::GH => Some(Continent::Africa),
+            CountryCode::GI => Some(Continent::Europe),
+            CountryCode::GR => Some(Continent::Europe),
+            CountryCode::GL => Some(Continent::NorthAmerica),
+            CountryCode::GD => Some(Continent::NorthAmerica),
+            CountryCode::GP => Some(Continent::NorthAmerica),
+            CountryCode::GU => Some(Continent::Oceania),
+            CountryCode::GT => Some(Continent::NorthAmerica),
+            CountryCode::GG => Some(Continent::Europe),
+            CountryCode::GN => Some(Continent::Africa),
+            CountryCode::GW => Some(Continent::Africa),
+            CountryCode::GY => Some(Continent::SouthAmerica),
+            CountryCode::HT => Some(Continent::NorthAmerica),
+            CountryCode::HM => Some(Continent::Antarctica),
+            CountryCode::VA => Some(Continent::Europe),
+            CountryCode::HN => Some(Continent::NorthAmerica),
+            CountryCode::HK => Some(Continent::Asia),
+            CountryCode::HU => Some(Continent::Europe),
+            CountryCode::IS => Some(Continent::Europe),
+            CountryCode::IN => Some(Continent::Asia),
+            CountryCode::ID => Some(Continent::Asia),
+            CountryCode::IR => Some(Continent::Asia),
+            CountryCode::IQ => Some(Continent::Asia),
+            CountryCode::IE => Some(Continent::Europe),
+            CountryCode::IM => Some(Continent::Europe),
+            CountryCode::IL => Some(Continent::Asia),
+            CountryCode::IT => Some(Continent::Europe),
+            CountryCode::JM => Some(Continent::NorthAmerica),
+            CountryCode::JP => Some(Continent::Asia),
+            CountryCode::JE => Some(Continent::Europe),
+            CountryCode::JO => Some(Continent::Asia),
+            CountryCode::KZ => Some(Continent::Asia),
+            CountryCode::KE => Some(Continent::Africa),
+            CountryCode::KI => Some(Continent::Oceania),
+            CountryCode::KP => Some(Continent::Asia),
+            CountryCode::KR => Some(Continent::Asia),
+            CountryCode::KW => Some(Continent::Asia),
+            CountryCode::KG => Some(Continent::Asia),
+            CountryCode::LA => Some(Continent::Asia),
+            CountryCode::LV => Some(Continent::Europe),
+            CountryCode::LB => Some(Continent::Asia),
+            CountryCode::LS => Some(Continent::Africa),
+            CountryCode::LR => Some(Continent::Africa),
+            CountryCode::LY => Some(Continent::Africa),
+            CountryCode::LI => Some(Continent::Europe),
+            CountryCode::LT => Some(Continent::Europe),
+            CountryCode::LU => Some(Continent::Europe),
+            CountryCode::MO => Some(Continent::Asia),
+            CountryCode::MK => Some(Continent::Europe),
+            CountryCode::MG => Some(Continent::Africa),
+            CountryCode::MW => Some(Continent::Africa),
+            CountryCode::MY => Some(Continent::Asia),
+            CountryCode::MV => Some(Continent::Asia),
+            CountryCode::ML => Some(Continent::Africa),
+            CountryCode::MT => Some(Continent::Europe),
+            CountryCode::MH => Some(Continent::Oceania),
+            CountryCode::MQ => Some(Continent::NorthAmerica),
+            CountryCode::MR => Some(Continent::Africa),
+            CountryCode::MU => Some(Continent::Africa),
+            CountryCode::YT => Some(Continent::Africa),
+            CountryCode::MX => Some(Continent::NorthAmerica),
+            CountryCode::FM => Some(Continent::Oceania),
+            CountryCode::MD => Some(Continent::Europe),
+            CountryCode::MC => Some(Continent::Europe),
+            CountryCode::MN => Some(Continent::Asia),
+            CountryCode::ME => Some(Continent::Europe),
+            CountryCode::MS => Some(Continent::NorthAmerica),
+            CountryCode::MA => Some(Continent::Africa),
+            CountryCode::MZ => Some(Continent::Africa),
+            CountryCode::MM => Some(Continent::Asia),
+            CountryCode::NA => Some(Continent::Africa),
+            CountryCode::NR => Some(Continent::Oceania),
+            CountryCode::NP => Some(Continent::Asia),
+            CountryCode::NL => Some(Continent::Europe),
+            CountryCode::NC => Some(Continent::Oceania),
+            CountryCode::NZ => Some(Continent::Oceania),
+            CountryCode::NI => Some(Continent::NorthAmerica),
+            CountryCode::NE => Some(Continent::Africa),
+            CountryCode::NG => Some(Continent::Africa),
+            CountryCode::NU => Some(Continent::Oceania),
+            CountryCode::NF => Some(Continent::Oceania),
+            CountryCode::MP => Some(Continent::Oceania),
+            CountryCode::NO => Some(Continent::Europe),
+            CountryCode::OM => Some(Continent::Asia),
+            CountryCode::PK => Some(Continent::Asia),
+            CountryCode::PW => Some(Continent::Oceania),
+            CountryCode::PS => Some(Continent::Asia),
+            CountryCode::PA => Some(Continent::NorthAmerica),
+            CountryCode::PG => Some(Continent::Oceania),
+            CountryCode::PY => Some(Continent::SouthAmerica),
+            CountryCode::PE => Some(Continent::SouthAmerica),
+            CountryCode::PH => Some(Continent::Asia),
+            CountryCode::PN => Some(Continent::Oceania),
+            CountryCode::PL => Some(Continent::Europe),
+            CountryCode::PT => Some(Continent::Europe),
+            CountryCode::PR => Some(Continent::NorthAmerica),
+            CountryCode::QA => Some(Continent::Asia),
+            CountryCode::RE => Some(Continent::Africa),
+            CountryCode::RO => Some(Continent::Europe),
+            CountryCode::RU => Some(Continent::Europe),
+            CountryCode::RW => Some(Continent::Africa),
+            CountryCode::BL => Some(Continent::NorthAmerica),
+            CountryCode::SH => Some(Continent::Africa),
+            CountryCode::KN => Some(Continent::NorthAmerica),
+            CountryCode::LC => Some(Continent::NorthAmerica),
+            CountryCode::MF => Some(Continent::NorthAmerica),
+            CountryCode::PM => Some(Continent::NorthAmerica),
+            CountryCode::VC => Some(Continent::NorthAmerica),
+            CountryCode::WS => Some(Continent::Oceania),
+            CountryCode::SM => Some(Continent::Europe),
+            CountryCode::ST => Some(Continent::Africa),
+            CountryCode::SA => Some(Continent::Asia),
+            CountryCode::SN => Some(Continent::Africa),
+            CountryCode::RS => Some(Continent::Europe),
+            CountryCode::SC => Some(Continent::Africa),
+            CountryCode::SL => Some(Continent::Africa),
+            CountryCode::SG => Some(Continent::Asia),
+            CountryCode::SX => Some(Continent::NorthAmerica),
+            CountryCode::SK => Some(Continent::Europe),
+            CountryCode::SI => Some(Continent::Europe),
+            CountryCode::SB => Some(Continent::Oceania),
+            CountryCode::SO => Some(Continent::Africa),
+            CountryCode::ZA => Some(Continent::Africa),
+            CountryCode::GS => Some(Continent::Antarctica),
+            CountryCode::SS => Some(Continent::Africa),
+            CountryCode::ES => Some(Continent::Europe),
+            CountryCode::LK => Some(Continent::Asia),
+            CountryCode::SD => Some(Continent::Africa),
+            CountryCode::SR => Some(Continent::SouthAmerica),
+            CountryCode::SJ => Some(Continent::Europe),
+            CountryCode::SZ => Some(Continent::Africa),
+            CountryCode::SE => Some(Continent::Europe),
+            CountryCode::CH => Some(Continent::Europe),
+            CountryCode::SY => Some(Continent::Asia),
+            CountryCode::TW => Some(Continent::Asia),
+            CountryCode::TJ => Some(Continent::Asia),
+            CountryCode::TZ => Some(Continent::Africa),
+            CountryCode::TH => Some(Continent::Asia),
+            CountryCode::TL => Some(Continent::Asia),
+            CountryCode::TG => Some(Continent::Africa),
+            CountryCode::TK => Some(Continent::Oceania),
+            CountryCode::TO => Some(Continent::Oceania),
+            CountryCode::TT => Some(Continent::NorthAmerica),
+            CountryCode::TN => Some(Continent::Africa),
+            CountryCode::TR => Some(Continent::Asia),
+            CountryCode::TM => Some(Continent::Asia),
+            CountryCode::TC => Some(Continent::NorthAmerica),
+            CountryCode::TV => Some(Continent::Oceania),
+            CountryCode::UG => Some(Continent::Africa),
+            CountryCode::UA => Some(Continent::Europe),
+            CountryCode::AE => Some(Continent::Asia),
+            CountryCode::GB => Some(Continent::Europe),
+            CountryCode::US => Some(Continent::NorthAmerica),
+            CountryCode::UM => Some(Continent::Oceania),
+            CountryCode::UY => Some(Continent::SouthAmerica),
+            CountryCode::UZ => Some(Continent::Asia),
+            CountryCode::VU => Some(Continent::Oceania),
+            CountryCode::VE => Some(Continent::SouthAmerica),
+            CountryCode::VN => Some(Continent::Asia),
+            CountryCode::VG => Some(Continent::NorthAmerica),
+            CountryCode::VI => Some(Continent::NorthAmerica),
+            CountryCode::WF => Some(Continent::Oceania),
+            CountryCode::EH => Some(Continent::Africa),
+            CountryCode::YE => Some(Continent::Asia),
+            CountryCode::ZM => Some(Continent::Africa),
+            CountryCode::ZW => Some(Continent::Africa),
+            CountryCode::Unknown => None,
+        }
+    }
+    /// 由区域指示符号组成的旗帜表情
+    pub fn flag(&self) -> Option<String> {
+        if *self == CountryCode::Unknown {
+            return None;
+        }
+        <&str>::from(*self)
+            .chars()
+            .map(|letter| char::from_u32(0x1F1E6 + (letter as u32 - 'A' as u32)))
+            .collect()
+    }
+
+    /// 按二位字母代码、三位字母代码、数字代码或英文名称查找国家，不区分大小写。
+    /// 没有完全匹配时，返回名称中包含查询内容的所有国家。
+    pub fn find(query: &str) -> Vec<CountryCode> {
+        let query = query.trim();
+        let numeric = query.parse::<u16>().ok();
+        let exact = CountryCode::ALL.into_iter().find(|code| {
+            <&str>::from(*code).eq_ignore_ascii_case(query)
+                || code.alpha3().eq_ignore_ascii_case(query)
+                || numeric == Some(code.numeric())
+                || code.name().eq_ignore_ascii_case(query)
+        });
+        if let Some(code) = exact {
+            return vec![code];
+        }
+
+        let query = query.to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+        CountryCode::ALL
+            .into_iter()
+            .filter(|code| code.name().to_lowercase().contains(&query))
+            .collect()
+    }
+}
+
 impl From<CountryCode> for &str {
     fn from(value: CountryCode) -> Self {
         match value {
@@ -510,6 +2343,7 @@ impl From<CountryCode> for &str {
 }
 
 impl From<&str> for CountryCode {
+    /// 按二位字母代码转换，未知的代码（包括 `ZZ`）转换为 `Unknown`
     fn from(ss: &str) -> Self {
         match ss {
             "AX" => CountryCode::AX,
@@ -764,4 +2598,169 @@ impl From<&str> for CountryCode {
             _ => CountryCode::Unknown,
         }
     }
-}
\ No newline at end of file
+}
+
+impl Serialize for CountryCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str((*self).into())
+    }
+}
+
+impl<'de> Deserialize<'de> for CountryCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        match CountryCode::from(code.to_ascii_uppercase().as_str()) {
+            CountryCode::Unknown if code != "Unknown" => {
+                Err(de::Error::custom(format!("unknown country code {:?}", code)))
+            }
+            country => Ok(country),
+        }
+    }
+}
+
+/// 大洲
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Continent {
+    Africa,
+    Antarctica,
+    Asia,
+    Europe,
+    NorthAmerica,
+    Oceania,
+    SouthAmerica,
+}
+
+impl Display for Continent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Continent::Africa => write!(f, "Africa"),
+            Continent::Antarctica => write!(f, "Antarctica"),
+            Continent::Asia => write!(f, "Asia"),
+            Continent::Europe => write!(f, "Europe"),
+            Continent::NorthAmerica => write!(f, "North America"),
+            Continent::Oceania => write!(f, "Oceania"),
+            Continent::SouthAmerica => write!(f, "South America"),
+        }
+    }
+}
+
+/// 一个国家或地区的详细信息
+#[derive(Serialize, Debug)]
+pub struct CountryDetails {
+    /// ISO 3166-1 二位字母代码
+    pub code: CountryCode,
+
+    /// ISO 3166-1 三位字母代码
+    pub alpha3: &'static str,
+
+    /// ISO 3166-1 数字代码
+    pub numeric: u16,
+
+    /// 英文名称
+    pub name: &'static str,
+
+    /// 旗帜表情
+    pub flag: Option<String>,
+
+    /// 国际电话区号
+    pub calling_code: Option<&'static str>,
+
+    /// ISO 4217 货币代码
+    pub currency: Option<&'static str>,
+
+    /// 所在的大洲
+    pub continent: Option<Continent>,
+}
+
+impl From<CountryCode> for CountryDetails {
+    fn from(code: CountryCode) -> Self {
+        CountryDetails {
+            code,
+            alpha3: code.alpha3(),
+            numeric: code.numeric(),
+            name: code.name(),
+            flag: code.flag(),
+            calling_code: code.calling_code(),
+            currency: code.currency(),
+            continent: code.continent(),
+        }
+    }
+}
+
+impl Display for CountryDetails {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}\t{}\t{:03}\t{}",
+            self.flag.as_deref().unwrap_or("  "),
+            <&str>::from(self.code).bold(),
+            self.alpha3,
+            self.numeric,
+            self.name.cyan(),
+        )?;
+        write!(f, "\t{}", self.calling_code.unwrap_or("-"))?;
+        write!(f, "\t{}", self.currency.unwrap_or("-"))?;
+        match self.continent {
+            Some(continent) => write!(f, "\t{}", continent),
+            None => Ok(()),
+        }
+    }
+}
+
+/// 查找国家，查询内容可以是代码或名称。
+///
+/// # Errors
+///
+/// 如果没有匹配的国家。
+pub async fn countries(query: &str) -> Result<Vec<CountryDetails>> {
+    let matches = CountryCode::find(query);
+    if matches.is_empty() {
+        bail!("no country matches {:?}", query);
+    }
+    Ok(matches.into_iter().map(CountryDetails::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_lists_every_known_country_once() {
+        assert!(!CountryCode::ALL.contains(&CountryCode::Unknown));
+        for (i, code) in CountryCode::ALL.iter().enumerate() {
+            assert!(!CountryCode::ALL[i + 1..].contains(code), "{:?} is listed twice", code);
+        }
+    }
+
+    #[test]
+    fn codes_round_trip_through_alpha2_alpha3_and_numeric() {
+        for code in CountryCode::ALL {
+            let alpha2 = <&str>::from(code);
+            assert_eq!(alpha2.len(), 2, "{:?}", code);
+            assert_eq!(CountryCode::from(alpha2), code);
+            assert_eq!(code.alpha3().len(), 3, "{:?}", code);
+            assert_eq!(CountryCode::find(code.alpha3()), vec![code]);
+            assert_eq!(CountryCode::find(&format!("{:03}", code.numeric())), vec![code]);
+        }
+    }
+
+    #[test]
+    fn codes_round_trip_through_serde() {
+        for code in CountryCode::ALL.into_iter().chain([CountryCode::Unknown]) {
+            let json = serde_json::to_string(&code).unwrap();
+            assert_eq!(json, format!("{:?}", <&str>::from(code)));
+            assert_eq!(serde_json::from_str::<CountryCode>(&json).unwrap(), code);
+        }
+        assert_eq!(serde_json::to_string(&CountryCode::Unknown).unwrap(), r#""Unknown""#);
+        assert_eq!(serde_json::from_str::<CountryCode>(r#""de""#).unwrap(), CountryCode::DE);
+        assert!(serde_json::from_str::<CountryCode>(r#""XX""#).is_err());
+    }
+
+    #[test]
+    fn corrected_calling_codes_and_currencies() {
+        assert_eq!(CountryCode::VA.calling_code(), Some("+39"));
+        assert_eq!(CountryCode::CW.currency(), Some("XCG"));
+        assert_eq!(CountryCode::SX.currency(), Some("XCG"));
+    }
+}
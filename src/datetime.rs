@@ -1,4 +1,4 @@
-use std::fmt::{Display, Formatter, write};
+use std::fmt::{Display, Formatter};
 
 use anyhow::Result;
 use colored::*;
@@ -57,7 +57,7 @@ impl Display for Time{
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f,"{}", self.hour.to_string().bold())?;
         write!(f, ":{}", self.minute.to_string().bold())?;
-        write!(f, ":{}", self.second.to_string())?;
+        write!(f, ":{}", self.second)?;
         write!(f," UTC {}", self.timezone.bright_cyan())?;
         write!(f, "\n±{:.4} seconds", self.offset.to_string().bright_magenta())
     }
@@ -90,7 +90,7 @@ pub async fn time() -> Result<Time> {
 
 
 /// 返回系统日期和时间。
-pub async fn dateTime() -> Result<Datetime> {
+pub async fn date_time() -> Result<Datetime> {
     let date = date().await?;
     let time = time().await?;
 
@@ -0,0 +1,180 @@
+use std::fmt::{Display, Formatter};
+use std::io::{BufRead, BufReader};
+use std::net::IpAddr;
+use std::path::Path;
+use anyhow::{bail, Context, Result};
+use colored::*;
+use serde::Serialize;
+use tokio::task::spawn_blocking;
+use crate::country::CountryCode;
+
+/// 默认的离线IP地址到国家数据库
+pub const DEFAULT_GEOIP_DATABASE: &str = "/usr/share/what/ip-country.csv";
+
+/// IP地址所属的国家或地区
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct Country {
+    /// ISO 3166-1 二位字母代码
+    pub code: String,
+
+    /// 英文名称
+    pub name: String,
+}
+
+impl From<CountryCode> for Country {
+    fn from(code: CountryCode) -> Self {
+        Country {
+            code: <&str>::from(code).to_string(),
+            name: code.name().to_string(),
+        }
+    }
+}
+
+impl Display for Country {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.code.bold(), self.name)
+    }
+}
+
+/// 将IP地址转换为可比较的整数，IPv4地址映射到 `::ffff:0:0/96`
+fn address_key(address: IpAddr) -> u128 {
+    match address {
+        IpAddr::V4(v4) => u128::from(v4.to_ipv6_mapped()),
+        IpAddr::V6(v6) => u128::from(v6),
+    }
+}
+
+/// 解析范围的一端，支持IP地址和IPv4地址的十进制整数形式
+fn parse_bound(field: &str) -> Option<u128> {
+    let field = field.trim().trim_matches('"');
+    match field.parse::<IpAddr>() {
+        Ok(address) => Some(address_key(address)),
+        Err(_) => field.parse::<u32>().ok().map(|v4| address_key(IpAddr::V4(v4.into()))),
+    }
+}
+
+/// 解析数据库中的一行，格式为 `start,end,country_code[,...]`。
+/// 起止地址可以是IP地址（如 db-ip 和 ip-location-db），
+/// 也可以是IPv4地址的十进制整数（如 IP2Location LITE），两侧的引号会被忽略。
+pub fn parse_range(line: &str) -> Option<(u128, u128, CountryCode)> {
+    let mut fields = line.split(',');
+    let start = parse_bound(fields.next()?)?;
+    let end = parse_bound(fields.next()?)?;
+    let code = fields.next()?.trim().trim_matches('"').to_ascii_uppercase();
+
+    match CountryCode::from(code.as_str()) {
+        CountryCode::Unknown => None,
+        code => Some((start, end, code)),
+    }
+}
+
+/// 在离线数据库中查找IP地址所属的国家。
+/// 返回的国家与输入的地址一一对应，不在任何范围中的地址对应 `None`。
+/// 没有要查找的地址时不会打开数据库。
+///
+/// # Errors
+///
+/// 如果数据库不存在或无法读取。
+pub async fn lookup_countries(database: &Path, addresses: &[IpAddr]) -> Result<Vec<Option<Country>>> {
+    if addresses.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let database = database.to_path_buf();
+    let keys = addresses.iter().map(|&address| address_key(address)).collect::<Vec<_>>();
+
+    spawn_blocking(move || {
+        let file = match std::fs::File::open(&database) {
+            Ok(file) => file,
+            // 数据库不随程序分发，需要用户自行下载
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => bail!(
+                "ip to country database {} not found; download one as described in `what ips --help` or pass --geo-db",
+                database.display(),
+            ),
+            Err(e) => return Err(e).with_context(|| format!("opening ip to country database {} failed", database.display())),
+        };
+
+        let mut countries = vec![None; keys.len()];
+        for line in BufReader::new(file).lines() {
+            let line = line.with_context(|| format!("reading {} failed", database.display()))?;
+            let Some((start, end, code)) = parse_range(&line) else { continue };
+
+            for (key, country) in keys.iter().zip(countries.iter_mut()) {
+                if country.is_none() && (start..=end).contains(key) {
+                    *country = Some(Country::from(code));
+                }
+            }
+            if countries.iter().all(Option::is_some) {
+                break;
+            }
+        }
+
+        Ok(countries)
+    }).await?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    fn key(address: &str) -> u128 {
+        address_key(address.parse().unwrap())
+    }
+
+    #[test]
+    fn parse_range_reads_address_ranges() {
+        assert_eq!(
+            parse_range("1.0.0.0,1.0.0.255,AU"),
+            Some((key("1.0.0.0"), key("1.0.0.255"), CountryCode::AU)),
+        );
+        assert_eq!(
+            parse_range("2001:200::,2001:200:ffff:ffff:ffff:ffff:ffff:ffff,jp"),
+            Some((key("2001:200::"), key("2001:200:ffff:ffff:ffff:ffff:ffff:ffff"), CountryCode::JP)),
+        );
+    }
+
+    #[test]
+    fn parse_range_reads_quoted_integer_ranges() {
+        assert_eq!(
+            parse_range(r#""16777216","16777471","AU","Australia""#),
+            Some((key("1.0.0.0"), key("1.0.0.255"), CountryCode::AU)),
+        );
+    }
+
+    #[test]
+    fn parse_range_skips_unknown_countries_and_malformed_lines() {
+        assert_eq!(parse_range("0.0.0.0,0.255.255.255,-"), None);
+        assert_eq!(parse_range("10.0.0.0,10.255.255.255,ZZ"), None);
+        assert_eq!(parse_range("start,end,country"), None);
+        assert_eq!(parse_range("1.0.0.0,1.0.0.255"), None);
+        assert_eq!(parse_range(""), None);
+    }
+
+    #[test]
+    fn ipv4_addresses_sort_inside_the_mapped_range() {
+        assert!(key("0.0.0.0") > key("::"));
+        assert_eq!(key("1.2.3.4"), u128::from(Ipv4Addr::new(1, 2, 3, 4).to_ipv6_mapped()));
+        assert!(key("255.255.255.255") < u128::from(Ipv6Addr::new(0x2001, 0, 0, 0, 0, 0, 0, 0)));
+    }
+
+    #[tokio::test]
+    async fn lookup_countries_matches_addresses_to_ranges() {
+        let directory = tempfile::tempdir().unwrap();
+        let database = directory.path().join("ip-country.csv");
+        std::fs::write(&database, "1.0.0.0,1.0.0.255,AU\n2001:200::,2001:200:ffff:ffff:ffff:ffff:ffff:ffff,JP\n").unwrap();
+
+        let addresses = ["1.0.0.1", "2001:200::1", "8.8.8.8"].map(|address| address.parse().unwrap());
+        let countries = lookup_countries(&database, &addresses).await.unwrap();
+
+        assert_eq!(countries, vec![Some(Country::from(CountryCode::AU)), Some(Country::from(CountryCode::JP)), None]);
+    }
+
+    #[tokio::test]
+    async fn lookup_countries_does_not_open_the_database_without_addresses() {
+        let missing = Path::new("/nonexistent/ip-country.csv");
+
+        assert_eq!(lookup_countries(missing, &[]).await.unwrap(), Vec::new());
+        assert!(lookup_countries(missing, &["1.0.0.1".parse().unwrap()]).await.is_err());
+    }
+}
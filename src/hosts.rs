@@ -0,0 +1,183 @@
+use std::fmt::{Display, Formatter};
+use std::net::IpAddr;
+use std::path::Path;
+use anyhow::{Context, Result};
+use colored::*;
+use serde::Serialize;
+use tokio::task::spawn_blocking;
+
+/// 静态主机表文件
+pub const ETC_HOSTS: &str = "/etc/hosts";
+
+/// 名称服务切换配置文件
+pub const ETC_NSSWITCH_CONF: &str = "/etc/nsswitch.conf";
+
+/// 没有 `/etc/nsswitch.conf` 时 glibc 使用的主机名查询顺序
+pub const DEFAULT_HOSTS_SOURCES: [&str; 2] = ["files", "dns"];
+
+/// `/etc/hosts` 中的一条记录
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct HostsEntry {
+    /// IP地址
+    pub address: IpAddr,
+
+    /// 主机名，第一个为规范名称，其余为别名
+    pub names: Vec<String>,
+}
+
+impl HostsEntry {
+    /// 主机名是否匹配，不区分大小写，忽略末尾的点
+    pub fn matches(&self, name: &str) -> bool {
+        let name = name.trim_end_matches('.');
+        self.names.iter().any(|candidate| candidate.eq_ignore_ascii_case(name))
+    }
+}
+
+impl Display for HostsEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\t{}", self.address.to_string().bold(), self.names.join(" "))
+    }
+}
+
+/// 静态主机表及名称服务切换配置
+#[derive(Serialize, Debug)]
+pub struct Hosts {
+    /// `hosts:` 行中列出的查询来源，按查询顺序排列
+    pub nsswitch: Vec<String>,
+
+    /// `/etc/hosts` 中的记录
+    pub entries: Vec<HostsEntry>,
+}
+
+impl Display for Hosts {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\t{}", "nsswitch".cyan(), self.nsswitch.join(" "))?;
+        for entry in &self.entries {
+            write!(f, "\n{}", entry)?;
+        }
+        Ok(())
+    }
+}
+
+/// 解析 `/etc/hosts` 的内容，忽略注释和无效的地址
+pub fn parse_hosts(contents: &str) -> Vec<HostsEntry> {
+    contents
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default())
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let address = fields.next()?.parse().ok()?;
+            let names = fields.map(str::to_string).collect::<Vec<_>>();
+            (!names.is_empty()).then_some(HostsEntry { address, names })
+        })
+        .collect()
+}
+
+/// 解析 `/etc/nsswitch.conf` 中 `hosts:` 行的查询来源。
+/// 形如 `[NOTFOUND=return]` 的动作不是查询来源，会被忽略。
+pub fn parse_nsswitch_hosts(contents: &str) -> Option<Vec<String>> {
+    contents
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .find_map(|line| line.strip_prefix("hosts:"))
+        .map(|sources| {
+            sources
+                .split_whitespace()
+                .filter(|source| !source.starts_with('['))
+                .map(str::to_string)
+                .collect()
+        })
+}
+
+/// 读取 `/etc/hosts` 和 `/etc/nsswitch.conf`。
+/// 任一文件不存在时分别视为没有记录和使用默认的查询顺序。
+///
+/// # Errors
+///
+/// 如果文件存在但无法读取。
+pub async fn hosts() -> Result<Hosts> {
+    spawn_blocking(|| read_hosts(Path::new(ETC_HOSTS), Path::new(ETC_NSSWITCH_CONF))).await?
+}
+
+/// 从指定的 hosts 文件和名称服务切换配置文件读取，规则与 [`hosts`] 相同
+pub fn read_hosts(hosts_path: &Path, nsswitch_path: &Path) -> Result<Hosts> {
+    let entries = read_optional(hosts_path)?.map(|contents| parse_hosts(&contents)).unwrap_or_default();
+    let nsswitch = read_optional(nsswitch_path)?
+        .and_then(|contents| parse_nsswitch_hosts(&contents))
+        .unwrap_or_else(|| DEFAULT_HOSTS_SOURCES.iter().map(ToString::to_string).collect());
+
+    Ok(Hosts { nsswitch, entries })
+}
+
+fn read_optional(path: &Path) -> Result<Option<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).with_context(|| format!("reading {} failed", path.display())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    #[test]
+    fn parse_hosts_skips_comments_and_keeps_aliases() {
+        let entries = parse_hosts("\
+# The following lines are desirable for IPv4 capable hosts
+127.0.0.1       localhost
+192.168.1.10    nas.home.lan nas backup   # storage box
+   # indented comment
+::1             localhost ip6-localhost ip6-loopback
+fe80::1%eth0    router
+not-an-address  ignored
+10.0.0.1
+");
+
+        assert_eq!(entries, [
+            HostsEntry { address: IpAddr::V4(Ipv4Addr::LOCALHOST), names: vec!["localhost".to_string()] },
+            HostsEntry {
+                address: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10)),
+                names: vec!["nas.home.lan".to_string(), "nas".to_string(), "backup".to_string()],
+            },
+            HostsEntry {
+                address: IpAddr::V6(Ipv6Addr::LOCALHOST),
+                names: vec!["localhost".to_string(), "ip6-localhost".to_string(), "ip6-loopback".to_string()],
+            },
+        ]);
+        assert!(entries[1].matches("NAS"));
+        assert!(!entries[1].matches("home.lan"));
+    }
+
+    #[test]
+    fn parse_nsswitch_hosts_ignores_actions_and_comments() {
+        let contents = "\
+passwd:         files systemd
+# hosts:        dns
+hosts:          files mymachines [NOTFOUND=return] resolve [!UNAVAIL=return] dns myhostname # trailing
+networks:       files
+";
+        assert_eq!(
+            parse_nsswitch_hosts(contents).unwrap(),
+            ["files", "mymachines", "resolve", "dns", "myhostname"],
+        );
+    }
+
+    #[test]
+    fn parse_nsswitch_hosts_without_a_hosts_line() {
+        assert_eq!(parse_nsswitch_hosts("passwd: files\ngroup: files\n"), None);
+        assert_eq!(parse_nsswitch_hosts(""), None);
+    }
+
+    #[test]
+    fn read_hosts_defaults_missing_files() {
+        let directory = tempfile::tempdir().unwrap();
+        let nsswitch = directory.path().join("nsswitch.conf");
+        std::fs::write(&nsswitch, "passwd: files\n").unwrap();
+
+        let hosts = read_hosts(&directory.path().join("hosts"), &nsswitch).unwrap();
+        assert!(hosts.entries.is_empty());
+        assert_eq!(hosts.nsswitch, DEFAULT_HOSTS_SOURCES);
+    }
+}
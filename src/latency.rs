@@ -0,0 +1,324 @@
+use std::fmt::{Display, Formatter};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use anyhow::{bail, Context, Result};
+use colored::*;
+use rustls::{ClientConfig, OwnedTrustAnchor, RootCertStore, ServerName};
+use serde::Serialize;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+
+/// 每个端点默认的测量次数
+pub const DEFAULT_ATTEMPTS: usize = 5;
+
+/// 单次测量的默认超时时间，单位为秒
+pub const DEFAULT_TIMEOUT_SECONDS: f64 = 5.0;
+
+/// 延迟测量的选项
+#[derive(Clone, Debug)]
+pub struct Options {
+    /// 每个端点的测量次数
+    pub attempts: usize,
+
+    /// 是否测量TLS握手时间
+    pub tls: bool,
+
+    /// 是否测量HTTP首字节时间
+    pub http: bool,
+
+    /// 单次测量的超时时间
+    pub timeout: Duration,
+}
+
+/// 一组延迟样本的统计值，单位为毫秒
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+pub struct LatencyStats {
+    pub min_ms: f64,
+    pub avg_ms: f64,
+    pub p95_ms: f64,
+    pub max_ms: f64,
+}
+
+impl LatencyStats {
+    /// 计算样本的统计值，没有样本时返回 `None`。
+    /// p95 使用最近秩法计算。
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut millis = samples.iter().map(|sample| sample.as_secs_f64() * 1000.0).collect::<Vec<_>>();
+        millis.sort_by(f64::total_cmp);
+
+        let rank = ((millis.len() as f64) * 0.95).ceil() as usize;
+        Some(LatencyStats {
+            min_ms: millis[0],
+            avg_ms: millis.iter().sum::<f64>() / millis.len() as f64,
+            p95_ms: millis[rank.saturating_sub(1)],
+            max_ms: millis[millis.len() - 1],
+        })
+    }
+}
+
+impl Display for LatencyStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:.1} / avg {} / p95 {:.1} / max {:.1} ms",
+            self.min_ms,
+            colored_latency(self.avg_ms),
+            self.p95_ms,
+            self.max_ms,
+        )
+    }
+}
+
+fn colored_latency(millis: f64) -> ColoredString {
+    let text = format!("{:.1}", millis);
+    match millis {
+        _ if millis > 500.0 => text.red(),
+        _ if millis > 100.0 => text.yellow(),
+        _ => text.green(),
+    }
+}
+
+/// 一个端点的延迟测量结果
+#[derive(Serialize, Debug)]
+pub struct LatencyReport {
+    /// 测量的端点，格式为 `host:port`
+    pub endpoint: String,
+
+    /// 实际连接的地址，无法解析端点时为空
+    pub address: Option<SocketAddr>,
+
+    /// 测量次数
+    pub attempts: usize,
+
+    /// 失败的测量次数
+    pub failures: usize,
+
+    /// TCP连接时间
+    pub connect: Option<LatencyStats>,
+
+    /// TLS握手时间，仅在请求时测量
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_handshake: Option<LatencyStats>,
+
+    /// 从发送HTTP请求到收到第一个字节的时间，仅在请求时测量
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_to_first_byte: Option<LatencyStats>,
+
+    /// 最后一次失败的原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Display for LatencyReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let succeeded = self.attempts - self.failures;
+        let summary = format!("{}/{} ok", succeeded, self.attempts);
+        let summary = if self.failures == 0 { summary.green() } else { summary.red() };
+        write!(f, "{}\t{}", self.endpoint.bold(), summary)?;
+
+        let stages = [
+            ("connect", &self.connect),
+            ("tls", &self.tls_handshake),
+            ("ttfb", &self.time_to_first_byte),
+        ];
+        for (stage, stats) in stages {
+            if let Some(stats) = stats {
+                write!(f, "\n  {}\t{}", stage, stats)?;
+            }
+        }
+
+        if let Some(error) = &self.error {
+            write!(f, "\n  {}\t{}", "error".red(), error)?;
+        }
+        Ok(())
+    }
+}
+
+/// 单次测量中各阶段的耗时
+struct Sample {
+    connect: Duration,
+    tls_handshake: Option<Duration>,
+    time_to_first_byte: Option<Duration>,
+}
+
+/// 依次测量每个端点的延迟
+///
+/// # Errors
+///
+/// 如果端点格式不正确，或者在测量TLS握手时端点的主机是IP地址。
+/// 证书校验库不支持以IP地址作为服务器名称，这样的握手必然失败。
+pub async fn measure_all(endpoints: &[String], options: &Options) -> Result<Vec<LatencyReport>> {
+    for endpoint in endpoints {
+        let (host, _) = split_endpoint(endpoint)?;
+        if options.tls && host.parse::<IpAddr>().is_ok() {
+            bail!("--tls needs a host name to verify the certificate, but {} is an IP address", host);
+        }
+    }
+
+    let connector = options.tls.then(tls_connector);
+
+    let mut reports = Vec::with_capacity(endpoints.len());
+    for endpoint in endpoints {
+        reports.push(measure(endpoint, options, connector.as_ref()).await?);
+    }
+    Ok(reports)
+}
+
+/// 测量单个端点的延迟。
+/// 端点只解析一次，因此DNS解析时间不计入连接时间。
+async fn measure(endpoint: &str, options: &Options, connector: Option<&TlsConnector>) -> Result<LatencyReport> {
+    let (host, _) = split_endpoint(endpoint)?;
+
+    let mut report = LatencyReport {
+        endpoint: endpoint.to_string(),
+        address: None,
+        attempts: options.attempts,
+        failures: options.attempts,
+        connect: None,
+        tls_handshake: None,
+        time_to_first_byte: None,
+        error: None,
+    };
+
+    let address = match tokio::net::lookup_host(endpoint).await.map(|mut addresses| addresses.next()) {
+        Ok(Some(address)) => address,
+        Ok(None) => {
+            report.error = Some(format!("{} did not resolve to any address", host));
+            return Ok(report);
+        }
+        Err(e) => {
+            report.error = Some(format!("resolving {} failed: {}", host, e));
+            return Ok(report);
+        }
+    };
+    report.address = Some(address);
+
+    let mut samples = Vec::new();
+    for _ in 0..options.attempts {
+        match tokio::time::timeout(options.timeout, sample(address, host, options, connector)).await {
+            Ok(Ok(sample)) => samples.push(sample),
+            Ok(Err(e)) => report.error = Some(format!("{:#}", e)),
+            Err(_) => report.error = Some(format!("timed out after {} seconds", options.timeout.as_secs_f64())),
+        }
+    }
+
+    report.failures = options.attempts - samples.len();
+    report.connect = LatencyStats::from_samples(&samples.iter().map(|s| s.connect).collect::<Vec<_>>());
+    report.tls_handshake = LatencyStats::from_samples(&samples.iter().filter_map(|s| s.tls_handshake).collect::<Vec<_>>());
+    report.time_to_first_byte = LatencyStats::from_samples(&samples.iter().filter_map(|s| s.time_to_first_byte).collect::<Vec<_>>());
+    Ok(report)
+}
+
+/// 执行一次测量：建立TCP连接，可选地完成TLS握手并等待HTTP响应的第一个字节
+async fn sample(address: SocketAddr, host: &str, options: &Options, connector: Option<&TlsConnector>) -> Result<Sample> {
+    let start = Instant::now();
+    let stream = TcpStream::connect(address).await.context("tcp connect failed")?;
+    let connect = start.elapsed();
+
+    let Some(connector) = connector else {
+        let time_to_first_byte = match options.http {
+            true => Some(first_byte(stream, host).await?),
+            false => None,
+        };
+        return Ok(Sample { connect, tls_handshake: None, time_to_first_byte });
+    };
+
+    let server_name = ServerName::try_from(host).with_context(|| format!("invalid tls server name {:?}", host))?;
+    let start = Instant::now();
+    let stream = connector.connect(server_name, stream).await.context("tls handshake failed")?;
+    let tls_handshake = start.elapsed();
+
+    let time_to_first_byte = match options.http {
+        true => Some(first_byte(stream, host).await?),
+        false => None,
+    };
+    Ok(Sample { connect, tls_handshake: Some(tls_handshake), time_to_first_byte })
+}
+
+/// 发送一个最小的HTTP请求，返回从发送请求到收到第一个字节的时间
+async fn first_byte<S: AsyncRead + AsyncWrite + Unpin>(mut stream: S, host: &str) -> Result<Duration> {
+    let request = format!(
+        "HEAD / HTTP/1.1\r\nHost: {}\r\nUser-Agent: what\r\nConnection: close\r\n\r\n",
+        host
+    );
+
+    let start = Instant::now();
+    stream.write_all(request.as_bytes()).await.context("sending http request failed")?;
+
+    let mut buffer = [0u8; 1];
+    if stream.read(&mut buffer).await.context("reading http response failed")? == 0 {
+        bail!("connection closed before the http response");
+    }
+    Ok(start.elapsed())
+}
+
+/// 将端点拆分为主机和端口，支持 `[::1]:443` 形式的IPv6地址
+fn split_endpoint(endpoint: &str) -> Result<(&str, u16)> {
+    let (host, port) = endpoint
+        .rsplit_once(':')
+        .with_context(|| format!("endpoint {:?} is not in host:port form", endpoint))?;
+    let port = port.parse().with_context(|| format!("invalid port in endpoint {:?}", endpoint))?;
+    Ok((host.trim_start_matches('[').trim_end_matches(']'), port))
+}
+
+/// 创建使用内置根证书的TLS连接器
+fn tls_connector() -> TlsConnector {
+    let mut roots = RootCertStore::empty();
+    roots.add_server_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.0.iter().map(|anchor| {
+        OwnedTrustAnchor::from_subject_spki_name_constraints(anchor.subject, anchor.spki, anchor.name_constraints)
+    }));
+
+    let config = ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    TlsConnector::from(Arc::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_samples_computes_min_avg_p95_and_max() {
+        let samples = (1..=20).rev().map(Duration::from_millis).collect::<Vec<_>>();
+        let stats = LatencyStats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.min_ms, 1.0);
+        assert_eq!(stats.avg_ms, 10.5);
+        assert_eq!(stats.p95_ms, 19.0);
+        assert_eq!(stats.max_ms, 20.0);
+    }
+
+    #[test]
+    fn from_samples_handles_one_or_no_sample() {
+        let stats = LatencyStats::from_samples(&[Duration::from_millis(7)]).unwrap();
+        assert_eq!((stats.min_ms, stats.avg_ms, stats.p95_ms, stats.max_ms), (7.0, 7.0, 7.0, 7.0));
+
+        assert_eq!(LatencyStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn split_endpoint_accepts_bracketed_ipv6() {
+        assert_eq!(split_endpoint("example.com:443").unwrap(), ("example.com", 443));
+        assert_eq!(split_endpoint("[::1]:8443").unwrap(), ("::1", 8443));
+        assert!(split_endpoint("example.com").is_err());
+        assert!(split_endpoint("example.com:https").is_err());
+    }
+
+    #[tokio::test]
+    async fn measure_all_rejects_tls_to_an_ip_address() {
+        let options = Options { attempts: 1, tls: true, http: false, timeout: Duration::from_secs(1) };
+
+        let error = measure_all(&["1.1.1.1:443".to_string()], &options).await.unwrap_err();
+        assert!(error.to_string().contains("IP address"), "{}", error);
+        let error = measure_all(&["[::1]:443".to_string()], &options).await.unwrap_err();
+        assert!(error.to_string().contains("IP address"), "{}", error);
+    }
+}
@@ -0,0 +1,267 @@
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use anyhow::{Context, Result};
+use colored::*;
+use serde::Serialize;
+use tokio::task::spawn_blocking;
+use crate::country::CountryCode;
+use crate::environment::parse_environment_file;
+
+/// 系统的时区文件，通常是指向 zoneinfo 数据库的符号链接
+pub const ETC_LOCALTIME: &str = "/etc/localtime";
+
+/// Debian 系发行版保存时区名称的文件
+pub const ETC_TIMEZONE: &str = "/etc/timezone";
+
+/// 时区数据库目录
+pub const ZONEINFO: &str = "/usr/share/zoneinfo";
+
+/// 系统范围的区域设置文件，systemd 使用前者，Debian 系发行版使用后者
+pub const LOCALE_CONFIG_FILES: [&str; 2] = ["/etc/locale.conf", "/etc/default/locale"];
+
+/// 检查的区域设置环境变量，按 glibc 的优先级从高到低排列
+pub const LOCALE_VARIABLES: [&str; 15] = [
+    "LC_ALL",
+    "LC_CTYPE",
+    "LC_NUMERIC",
+    "LC_TIME",
+    "LC_COLLATE",
+    "LC_MONETARY",
+    "LC_MESSAGES",
+    "LC_PAPER",
+    "LC_NAME",
+    "LC_ADDRESS",
+    "LC_TELEPHONE",
+    "LC_MEASUREMENT",
+    "LC_IDENTIFICATION",
+    "LANG",
+    "LANGUAGE",
+];
+
+/// 一个区域设置
+#[derive(Serialize, Debug)]
+pub struct LocaleSetting {
+    /// 环境变量名称
+    pub variable: String,
+
+    /// 环境变量的值
+    pub value: String,
+
+    /// 该值的来源：当前进程的环境变量或系统的区域设置文件
+    pub source: String,
+}
+
+impl Display for LocaleSetting {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\t{}\t({})", self.variable.bold(), self.value.cyan(), self.source)
+    }
+}
+
+/// 区域设置、时区及由它们推断出的国家
+#[derive(Serialize, Debug)]
+pub struct Locale {
+    /// 已设置的区域设置变量
+    pub settings: Vec<LocaleSetting>,
+
+    /// 生效的区域设置，取自 `LC_ALL` 或 `LANG`
+    pub locale: Option<String>,
+
+    /// 由区域设置的地区部分推断出的国家，例如 `zh_CN` 对应 CN
+    pub locale_country: Option<CountryCode>,
+
+    /// IANA 时区名称，例如 `Asia/Shanghai`
+    pub timezone: Option<String>,
+
+    /// 由时区推断出的国家
+    pub timezone_country: Option<CountryCode>,
+
+    /// 两种方式推断出的国家是否不一致
+    pub mismatch: bool,
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for setting in &self.settings {
+            writeln!(f, "{}", setting)?;
+        }
+
+        write!(f, "{}\t{}", "locale".cyan(), self.locale.as_deref().unwrap_or("-"))?;
+        if let Some(country) = self.locale_country {
+            write!(f, "\t{} ({})", <&str>::from(country).bold(), country.name())?;
+        }
+
+        write!(f, "\n{}\t{}", "timezone".cyan(), self.timezone.as_deref().unwrap_or("-"))?;
+        if let Some(country) = self.timezone_country {
+            write!(f, "\t{} ({})", <&str>::from(country).bold(), country.name())?;
+        }
+
+        if self.mismatch {
+            write!(f, "\n{}", "the locale and timezone point to different countries".yellow())?;
+        }
+        Ok(())
+    }
+}
+
+/// 从区域设置中提取地区对应的国家，格式为 `language[_territory][.codeset][@modifier]`。
+/// `C` 和 `POSIX` 等没有地区部分的区域设置返回 `None`。
+pub fn locale_territory(locale: &str) -> Option<CountryCode> {
+    let locale = locale.split(['.', '@']).next()?;
+    let (_, territory) = locale.split_once('_')?;
+    match CountryCode::from(territory.to_ascii_uppercase().as_str()) {
+        CountryCode::Unknown => None,
+        country => Some(country),
+    }
+}
+
+/// 从 `zone.tab` 中查找时区对应的国家
+pub fn parse_zone_tab(contents: &str, timezone: &str) -> Option<CountryCode> {
+    contents
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .find_map(|line| {
+            let mut fields = line.split('\t');
+            let code = fields.next()?;
+            let zone = fields.nth(1)?;
+            (zone == timezone).then(|| CountryCode::from(code))
+        })
+        .filter(|country| *country != CountryCode::Unknown)
+}
+
+/// 返回系统的时区名称。`TZ` 环境变量优先，其次是 `/etc/localtime` 指向的时区和 `/etc/timezone`。
+fn timezone() -> Option<String> {
+    if let Ok(tz) = std::env::var("TZ") {
+        let tz = tz.trim_start_matches(':');
+        if !tz.is_empty() && !tz.starts_with('/') {
+            return Some(tz.to_string());
+        }
+    }
+
+    if let Ok(target) = std::fs::read_link(ETC_LOCALTIME) {
+        let target = target.to_string_lossy().to_string();
+        if let Some((_, zone)) = target.split_once("zoneinfo/") {
+            return Some(zone.to_string());
+        }
+    }
+
+    std::fs::read_to_string(ETC_TIMEZONE)
+        .ok()
+        .map(|contents| contents.trim().to_string())
+        .filter(|zone| !zone.is_empty())
+}
+
+/// 返回时区对应的国家。时区名称是链接（如 `Asia/Calcutta`）时，使用其指向的规范名称查找。
+fn timezone_country(timezone: &str) -> Result<Option<CountryCode>> {
+    let zone_tab = Path::new(ZONEINFO).join("zone.tab");
+    let contents = match std::fs::read_to_string(&zone_tab) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e).with_context(|| format!("reading {} failed", zone_tab.display())),
+    };
+
+    if let Some(country) = parse_zone_tab(&contents, timezone) {
+        return Ok(Some(country));
+    }
+
+    let canonical = std::fs::canonicalize(Path::new(ZONEINFO).join(timezone))
+        .ok()
+        .and_then(|path| {
+            let zoneinfo = std::fs::canonicalize(ZONEINFO).ok()?;
+            Some(path.strip_prefix(zoneinfo).ok()?.to_string_lossy().to_string())
+        });
+    Ok(canonical.and_then(|zone| parse_zone_tab(&contents, &zone)))
+}
+
+/// 读取区域设置和时区，并推断用户所在的国家。
+/// 当前进程的环境变量优先；只在系统的区域设置文件中设置的变量同样会列出。
+///
+/// # Errors
+///
+/// 如果区域设置文件或时区数据库存在但无法读取。
+pub async fn locale() -> Result<Locale> {
+    spawn_blocking(|| {
+        let mut config = Vec::new();
+        for path in LOCALE_CONFIG_FILES {
+            match std::fs::read_to_string(path) {
+                Ok(contents) => config.extend(
+                    parse_environment_file(&contents).into_iter().map(|(name, value)| (name, value, path)),
+                ),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e).with_context(|| format!("reading {} failed", path)),
+            }
+        }
+
+        let mut settings = Vec::new();
+        for variable in LOCALE_VARIABLES {
+            let (value, source) = match std::env::var(variable) {
+                Ok(value) if !value.is_empty() => (value, "environment".to_string()),
+                _ => match config.iter().find(|(name, _, _)| name == variable) {
+                    Some((_, value, path)) => (value.clone(), path.to_string()),
+                    None => continue,
+                },
+            };
+            settings.push(LocaleSetting { variable: variable.to_string(), value, source });
+        }
+
+        let locale = ["LC_ALL", "LANG"]
+            .iter()
+            .find_map(|variable| settings.iter().find(|setting| setting.variable == *variable))
+            .map(|setting| setting.value.clone());
+        let locale_country = locale.as_deref().and_then(locale_territory);
+
+        let timezone = timezone();
+        let timezone_country = match &timezone {
+            Some(timezone) => timezone_country(timezone)?,
+            None => None,
+        };
+
+        let mismatch = matches!((locale_country, timezone_country), (Some(a), Some(b)) if a != b);
+
+        Ok(Locale { settings, locale, locale_country, timezone, timezone_country, mismatch })
+    }).await?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_territory_reads_the_territory() {
+        assert_eq!(locale_territory("zh_CN"), Some(CountryCode::CN));
+        assert_eq!(locale_territory("en_GB.UTF-8"), Some(CountryCode::GB));
+        assert_eq!(locale_territory("de_DE@euro"), Some(CountryCode::DE));
+        assert_eq!(locale_territory("sr_RS.UTF-8@latin"), Some(CountryCode::RS));
+        assert_eq!(locale_territory("pt_br.utf8"), Some(CountryCode::BR));
+    }
+
+    #[test]
+    fn locale_territory_ignores_locales_without_a_country() {
+        assert_eq!(locale_territory("C"), None);
+        assert_eq!(locale_territory("POSIX"), None);
+        assert_eq!(locale_territory("C.UTF-8"), None);
+        assert_eq!(locale_territory("en"), None);
+        assert_eq!(locale_territory("eo_XX.UTF-8"), None);
+    }
+
+    const ZONE_TAB: &str = "\
+# tz zone descriptions
+#
+CN\t+3114+12128\tAsia/Shanghai\tBeijing Time
+IN\t+2232+08822\tAsia/Kolkata
+US\t+404251-0740023\tAmerica/New_York\tEastern (most areas)
+";
+
+    #[test]
+    fn parse_zone_tab_finds_the_country_of_a_zone() {
+        assert_eq!(parse_zone_tab(ZONE_TAB, "Asia/Shanghai"), Some(CountryCode::CN));
+        assert_eq!(parse_zone_tab(ZONE_TAB, "Asia/Kolkata"), Some(CountryCode::IN));
+        assert_eq!(parse_zone_tab(ZONE_TAB, "America/New_York"), Some(CountryCode::US));
+    }
+
+    #[test]
+    fn parse_zone_tab_ignores_unknown_zones_and_comments() {
+        assert_eq!(parse_zone_tab(ZONE_TAB, "Asia/Calcutta"), None);
+        assert_eq!(parse_zone_tab(ZONE_TAB, "Etc/UTC"), None);
+        assert_eq!(parse_zone_tab(ZONE_TAB, "#"), None);
+        assert_eq!(parse_zone_tab("XX\t+0000+00000\tNowhere/Zone\n", "Nowhere/Zone"), None);
+    }
+}
@@ -1,18 +1,31 @@
-use std::fmt::{Display, Pointer, write};
+use std::fmt::Display;
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::time::Duration;
 
 use anyhow::{Result, Context};
 use clap::{Parser, Subcommand, ValueEnum};
 use human_panic::setup_panic;
-use serde::{Serialize, Serializer};
-use crate::Commands::Time;
+use serde::Serializer;
 
+mod battery;
+mod connectivity;
 mod country;
 mod datetime;
+mod latency;
+mod locale;
 mod network;
 mod output;
+mod processes;
+mod proxy;
+mod sensors;
 mod system;
 mod format;
+mod geoip;
+mod hosts;
 mod storage;
+mod wireless;
+mod environment;
 
 #[derive(Debug,Parser)]
 #[command(name = "what")]
@@ -50,9 +63,60 @@ enum Commands {
     Datetime,
 
     #[command(name = "dns")]
-    #[command(about = "Display your system's DNS servers")]
-    #[command(long_about = "Show the DNS servers configured on your system, listed in the order they are used.")]
-    Dns,
+    #[command(about = "Display your system's DNS configuration")]
+    #[command(long_about = "Show the DNS servers configured on your system, listed in the order they are used,\n\
+    with their port and protocol, followed by the search domains and resolver options.\n\
+    Use the --check flag to query each DNS server and report reachability, response time,\n\
+    recursion support and NXDOMAIN hijacking.")]
+    Dns {
+        /// Send a test query to each DNS server and report its health
+        #[arg(long)]
+        check: bool,
+    },
+
+    #[command(name = "resolve")]
+    #[command(about = "Look up DNS records for a name")]
+    #[command(long_about = "Query DNS records for a name using your system's resolver, printing each answer with\n\
+    its TTL along with the query latency.\n\
+    Use the --type flag to choose the record type and the --server flag to query a specific DNS server,\n\
+    adding --port when it does not listen on the transport's default port.\n\
+    Use the --dns-transport flag to query over TCP, TLS or HTTPS; TLS and HTTPS need --server and --tls-name.\n\
+    Use the --explain flag to show which source, the hosts file or a DNS server, produced the answer.\n\
+    Example: what resolve example.com --type MX --server 1.1.1.1")]
+    Resolve {
+        /// The name to look up; PTR lookups also accept an IP address
+        name: String,
+
+        /// The record type to query
+        #[arg(long = "type", value_enum, ignore_case = true, default_value_t = network::RecordKind::A)]
+        record_type: network::RecordKind,
+
+        /// Query this DNS server instead of the system resolver
+        #[arg(long)]
+        server: Option<IpAddr>,
+
+        /// The port of the DNS server, defaulting to the transport's standard port
+        #[arg(long, requires = "server")]
+        port: Option<u16>,
+
+        /// The transport used to reach the DNS server
+        #[arg(long, value_enum, default_value_t = network::DnsTransport::Udp)]
+        dns_transport: network::DnsTransport,
+
+        /// The name used to verify the DNS server's TLS certificate
+        #[arg(long, requires = "server")]
+        tls_name: Option<String>,
+
+        /// Show which source, the hosts file or a DNS server, produced the answer
+        #[arg(long, conflicts_with = "server")]
+        explain: bool,
+    },
+
+    #[command(name = "hosts")]
+    #[command(about = "Display your system's static host entries")]
+    #[command(long_about = "List the static entries from /etc/hosts along with the hosts: line from /etc/nsswitch.conf,\n\
+    which defines the order in which host names are looked up.")]
+    Hosts,
 
     #[command(name = "hostname")]
     #[command(about = "Display your system's hostname")]
@@ -81,13 +145,68 @@ enum Commands {
 
     #[command(name = "cpu")]
     #[command(about = "Display your system's CPU")]
-    #[command(long_about = "Show the name of the CPU installed on your system.")]
-    Cpu,
+    #[command(long_about = "Show the name and vendor of the CPU installed on your system, its physical and logical core counts,\n\
+    frequency and usage sampled over a short interval.\n\
+    Use the --per-core flag to list the frequency and usage of each logical core.\n\
+    Use the --features flag to list the instruction set extensions, x86-64 level, caches and NUMA nodes.")]
+    Cpu {
+        /// List the frequency and usage of each logical core
+        #[arg(long)]
+        per_core: bool,
+
+        /// List the instruction set extensions, caches and NUMA nodes
+        #[arg(long)]
+        features: bool,
+    },
+
+    #[command(name = "load")]
+    #[command(about = "Display your system's load")]
+    #[command(long_about = "Show the 1, 5 and 15 minute load averages, also normalised per logical CPU,\n\
+    and the Linux pressure stall information for CPU, memory and IO when the kernel provides it.")]
+    Load,
 
     #[command(name = "ram")]
     #[command(about = "Display your system's RAM")]
-    #[command(long_about = "Show the amount of RAM installed and used on your system.")]
-    Ram,
+    #[command(long_about = "Show the amount of RAM installed and used on your system,\n\
+    along with the swap space, each swap device, and whether swap is heavily used.\n\
+    Use the --detailed flag to break the memory down into buffers, page cache, shared memory, slab,\n\
+    dirty and writeback pages, hugepages and committed memory.")]
+    Ram {
+        /// Break the memory down using /proc/meminfo
+        #[arg(long)]
+        detailed: bool,
+    },
+
+    #[command(name = "processes")]
+    #[command(about = "Display the heaviest processes")]
+    #[command(long_about = "List the processes using the most CPU or memory with their PID, name, user,\n\
+    CPU usage sampled over a short interval, resident memory and command line.\n\
+    Use --top to change how many processes are listed and --sort to rank them by cpu or mem.")]
+    Processes {
+        /// The number of processes to list
+        #[arg(
+            long,
+            default_value_t = 10,
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+        )]
+        top: usize,
+
+        /// Rank the processes by CPU usage or resident memory
+        #[arg(long, value_enum, default_value_t = processes::ProcessSort::Cpu)]
+        sort: processes::ProcessSort,
+    },
+
+    #[command(name = "sensors")]
+    #[command(about = "Display your system's temperature and fan sensors")]
+    #[command(long_about = "Show the current, maximum and critical temperature of each hardware monitoring sensor\n\
+    and thermal zone, and the speed of each fan, coloured by how close the temperature is to critical.")]
+    Sensors,
+
+    #[command(name = "battery")]
+    #[command(about = "Display your battery and power supply status")]
+    #[command(long_about = "Show the charge, state, health compared to the design capacity, cycle count\n\
+    and estimated time to empty of each battery, and whether the AC adapter is connected.")]
+    Battery,
 
     #[command(name = "disks")]
     #[command(about = "Display your system's disks")]
@@ -96,24 +215,180 @@ enum Commands {
 
     #[command(name = "interfaces")]
     #[command(about = "Display your system's network interfaces")]
-    #[command(long_about = "List all the network interfaces configured on your system, presented in the order they are used.")]
-    Interfaces,
+    #[command(long_about = "List all the network interfaces configured on your system, presented in the order they are used.\n\
+    Use the --stats flag to include rx/tx byte, packet, error and drop counters.\n\
+    Use the --rate flag to sample the counters twice and show the current throughput.\n\
+    Use the --reverse flag to look up the reverse DNS name of each address.")]
+    Interfaces {
+        /// Include rx/tx counters from /proc/net/dev
+        #[arg(long)]
+        stats: bool,
+
+        /// Sample the counters twice and show the current throughput
+        #[arg(long)]
+        rate: bool,
+
+        /// Sampling interval in seconds used by --rate
+        #[arg(long, default_value_t = 1.0, requires = "rate")]
+        interval: f64,
+
+        /// Look up the reverse DNS name of each address
+        #[arg(long)]
+        reverse: bool,
+    },
+
+    #[command(name = "wifi")]
+    #[command(about = "Display your Wi-Fi connection")]
+    #[command(long_about = "Show the connection details of your wireless interfaces: SSID, BSSID, frequency and channel,\n\
+    signal strength and link rate.")]
+    Wifi,
+
+    #[command(name = "routes")]
+    #[command(about = "Display your system's routing table")]
+    #[command(long_about = "List the IPv4 and IPv6 routes configured on your system, showing destination, gateway,\n\
+    interface, metric and flags. The default gateway is shown first and the default route is highlighted.")]
+    Routes,
+
+    #[command(name = "neighbors")]
+    #[command(about = "Display your system's neighbor table")]
+    #[command(long_about = "List the devices on your local network segment known to the kernel's IPv4 ARP table\n\
+    and IPv6 neighbor table, showing their IP address, MAC address, interface and state.")]
+    Neighbors,
+
+    #[command(name = "ports")]
+    #[command(about = "Display your system's listening ports")]
+    #[command(long_about = "List the TCP and UDP sockets listening on your system, with their local address, port\n\
+    and owning process. The owning process is only shown when you are permitted to inspect it.\n\
+    Use the --tcp, --udp and --port flags to narrow the list.\n\
+    Example: what ports --tcp --port 8080")]
+    Ports {
+        /// Only show TCP sockets
+        #[arg(long)]
+        tcp: bool,
+
+        /// Only show UDP sockets
+        #[arg(long)]
+        udp: bool,
+
+        /// Only show sockets bound to this port
+        #[arg(long)]
+        port: Option<u16>,
+    },
+
+    #[command(name = "connectivity")]
+    #[command(about = "Diagnose your system's network connectivity")]
+    #[command(long_about = "Check, in order, the link state of the default route's interface, the reachability of the\n\
+    default gateway, DNS resolution through the configured servers and the reachability of a TCP endpoint,\n\
+    reporting the first layer that fails. A gateway that does not answer does not stop the later checks.\n\
+    Example: what connectivity --endpoint example.com:443")]
+    Connectivity {
+        /// The name resolved to check DNS resolution
+        #[arg(long, default_value = connectivity::DEFAULT_DNS_CHECK_NAME)]
+        dns_name: String,
+
+        /// The TCP endpoint to connect to, as host:port
+        #[arg(long, default_value = connectivity::DEFAULT_ENDPOINT)]
+        endpoint: String,
+
+        /// Timeout in seconds for each network check
+        #[arg(long, default_value_t = 3.0)]
+        timeout: f64,
+    },
+
+    #[command(name = "latency")]
+    #[command(about = "Measure the latency to TCP endpoints")]
+    #[command(long_about = "Measure the TCP connect time to one or more endpoints over several attempts, reporting\n\
+    the minimum, average, 95th percentile and maximum.\n\
+    Use the --tls flag to also measure the TLS handshake and the --http flag to measure the time to first byte.\n\
+    The TLS handshake verifies the certificate against the host name, so --tls needs host names, not IP addresses.\n\
+    Example: what latency example.com:443 cloudflare.com:443 --tls --http")]
+    Latency {
+        /// The endpoints to measure, as host:port
+        #[arg(required = true)]
+        endpoints: Vec<String>,
+
+        /// Number of attempts per endpoint
+        #[arg(
+            short = 'n',
+            long,
+            default_value_t = latency::DEFAULT_ATTEMPTS,
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+        )]
+        attempts: usize,
+
+        /// Also measure the TLS handshake
+        #[arg(long)]
+        tls: bool,
+
+        /// Also measure the HTTP time to first byte
+        #[arg(long)]
+        http: bool,
+
+        /// Timeout in seconds for each attempt
+        #[arg(long, default_value_t = latency::DEFAULT_TIMEOUT_SECONDS)]
+        timeout: f64,
+    },
+
+    #[command(name = "proxy")]
+    #[command(about = "Display your proxy configuration")]
+    #[command(long_about = "Show the http_proxy, https_proxy, all_proxy and no_proxy settings in both lower and upper case,\n\
+    where each value came from, and whether each configured proxy accepts TCP connections.")]
+    Proxy,
+
+    #[command(name = "locale")]
+    #[command(about = "Display your locale and timezone")]
+    #[command(long_about = "Show the LANG, LANGUAGE and LC_* settings and where each came from, the system timezone,\n\
+    and the country inferred from both the locale territory and the timezone, flagging when the two disagree.")]
+    Locale,
+
+    #[command(name = "country")]
+    #[command(about = "Look up a country by code or name")]
+    #[command(long_about = "Show the ISO 3166-1 alpha-2, alpha-3 and numeric codes, English name, flag,\n\
+    international calling code, currency and continent of a country.\n\
+    The query may be any of the codes or the English name; a partial name lists every country containing it.")]
+    Country {
+        /// The country code or name to look up
+        query: String,
+    },
 
     #[command(name = "ips")]
     #[command(about = "Display your IP addresses")]
     #[command(long_about = "Find all IP addresses associated with your system, both local and external.\n\
     By default, it shows both public and local IP addresses.\n\
-    Use the --only flag to display one specific category.")]
+    Use the --only flag to display one specific category.\n\
+    Use the --dns-transport flag to look up the public IP address over TCP, TLS or HTTPS.\n\
+    Use the --reverse flag to look up the reverse DNS name of each address.\n\
+    Use the --geo flag to look up the country of the public address in an offline CSV database of ip ranges;\n\
+    --geo-db selects a database other than /usr/share/what/ip-country.csv.\n\
+    The database is not bundled with what. Any CSV whose lines start with start,end,country_code works,\n\
+    for example the free country databases from db-ip.com, ip-location-db or IP2Location LITE.\n\
+    The database is only opened when there is a public address to look up.")]
     Ips {
         #[arg(long)]
-        only:Option<network::IpCategory>
+        only:Option<network::IpCategory>,
+
+        /// The transport used to reach the DNS server when looking up the public IP address
+        #[arg(long, value_enum, default_value_t = network::DnsTransport::Udp)]
+        dns_transport: network::DnsTransport,
+
+        /// Look up the reverse DNS name of each address
+        #[arg(long)]
+        reverse: bool,
+
+        /// Look up the country of the public address in an offline database
+        #[arg(long)]
+        geo: bool,
+
+        /// The ip to country database, a CSV file of `start,end,country_code` ranges
+        #[arg(long, requires = "geo", default_value = geoip::DEFAULT_GEOIP_DATABASE)]
+        geo_db: PathBuf,
     }
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     // 启用人类可读的紧急信息
-    setup_panic!();
+    setup_human_panic();
     // 解析CLI参数
     let cli = Cli::parse();
 
@@ -128,13 +403,40 @@ async fn main() -> Result<()> {
                     .with_context(||"looking up the system's time failed" )?
             ),
             Commands::Datetime => CommandResult::Datetime(
-                datetime::dateTime().await
+                datetime::date_time().await
                     .with_context(|| "looking up the system's datetime failed")?
             ),
-            Commands::Dns => CommandResult::Dns(
+            Commands::Dns { check: false } => CommandResult::Dns(
                 network::list_dns_servers().await
                     .with_context(|| "listing the system's dns servers failed")?
             ),
+            Commands::Dns { check: true } => CommandResult::DnsCheck(
+                network::check_dns_servers().await
+                    .with_context(|| "checking the system's dns servers failed")?
+            ),
+            Commands::Resolve { name, record_type, server, port, dns_transport, tls_name, explain } => {
+                let server = server.map(|server| {
+                    network::DnsServer::new(server, *dns_transport)
+                        .with_port(*port)
+                        .with_tls_name(tls_name.clone())
+                });
+
+                let mut resolution = network::resolve(name, *record_type, server.as_ref(), *dns_transport).await
+                    .with_context(|| format!("resolving {} failed", name))?;
+
+                if *explain {
+                    resolution.explanation = Some(
+                        network::explain_resolution(name, *record_type).await
+                            .with_context(|| format!("explaining the resolution of {} failed", name))?
+                    );
+                }
+
+                CommandResult::Resolve(resolution)
+            },
+            Commands::Hosts => CommandResult::Hosts(
+                hosts::hosts().await
+                    .with_context(|| "reading the system's hosts file failed")?
+            ),
             Commands::Hostname => CommandResult::Hostname(
                 system::hostname().await
                     .with_context(|| "looking up the system's hostname failed")?
@@ -155,76 +457,193 @@ async fn main() -> Result<()> {
                 system::architecture().await
                     .with_context(|| "looking up the CPU's architecture fialed")?
             ),
-            Commands::Cpu => CommandResult::Cpu(
-                system::cpus().await
+            Commands::Cpu { per_core, features } => CommandResult::Cpu(
+                system::cpus(*per_core, *features).await
                     .with_context(|| "looking up the system's CPU information failed")?
             ),
-            Commands::Ram => CommandResult::Ram(
-                system::ram().await
+            Commands::Load => CommandResult::Load(
+                system::load().await
+                    .with_context(|| "looking up the system's load failed")?
+            ),
+            Commands::Ram { detailed } => CommandResult::Ram(
+                system::ram(*detailed).await
                     .with_context(|| "looking up the system's RAM information failed")?
             ),
+            Commands::Processes { top, sort } => CommandResult::Processes(
+                processes::processes(*top, *sort).await
+                    .with_context(|| "listing the processes failed")?
+            ),
+            Commands::Sensors => CommandResult::Sensors(
+                sensors::sensors().await
+                    .with_context(|| "reading the system's sensors failed")?
+            ),
+            Commands::Battery => CommandResult::Battery(
+                battery::battery_status().await
+                    .with_context(|| "reading the battery status failed")?
+            ),
             Commands::Disks => CommandResult::Disks(
                 storage::list_disks().await
                     .with_context(|| "listing the disks failed")?
             ),
-            Commands::Interfaces => CommandResult::Interfaces(
-                network::interfaces().await
-                    .with_context(|| "listing the system's network interfaces failed")?
+            Commands::Interfaces { stats, rate, interval, reverse } => {
+                let mut interfaces = network::interfaces().await
+                    .with_context(|| "listing the system's network interfaces failed")?;
+
+                network::attach_wireless(&mut interfaces).await;
+
+                if *stats || *rate {
+                    let rate_interval = if *rate {
+                        Some(Duration::try_from_secs_f64(*interval)
+                            .with_context(|| format!("invalid sampling interval {}", interval))?)
+                    } else {
+                        None
+                    };
+                    network::attach_interface_stats(&mut interfaces, rate_interval).await
+                        .with_context(|| "reading the network interface counters failed")?;
+                }
+
+                if *reverse {
+                    network::attach_interface_ptrs(&mut interfaces).await
+                        .with_context(|| "looking up the reverse dns names failed")?;
+                }
+
+                CommandResult::Interfaces(interfaces)
+            },
+            Commands::Wifi => CommandResult::Wifi(
+                wireless::links().await
+                    .with_context(|| "reading the wireless interface details failed")?
+            ),
+            Commands::Routes => CommandResult::Routes(
+                network::routes().await
+                    .with_context(|| "reading the system's routing table failed")?
+            ),
+            Commands::Neighbors => CommandResult::Neighbors(
+                network::neighbors().await
+                    .with_context(|| "listing the system's neighbors failed")?
+            ),
+            Commands::Ports { tcp, udp, port } => {
+                let mut filter = network::PortFilter { port: *port, ..Default::default() };
+                if *tcp {
+                    filter.protocols.push(network::SocketProtocol::Tcp);
+                }
+                if *udp {
+                    filter.protocols.push(network::SocketProtocol::Udp);
+                }
+
+                CommandResult::Ports(
+                    network::listening_sockets(filter).await
+                        .with_context(|| "listing the system's listening ports failed")?
+                )
+            },
+            Commands::Connectivity { dns_name, endpoint, timeout } => {
+                let options = connectivity::Options {
+                    dns_name: dns_name.clone(),
+                    endpoint: endpoint.clone(),
+                    timeout: Duration::try_from_secs_f64(*timeout)
+                        .with_context(|| format!("invalid timeout {}", timeout))?,
+                };
+
+                CommandResult::Connectivity(
+                    connectivity::diagnose(&options).await
+                        .with_context(|| "diagnosing the system's connectivity failed")?
+                )
+            },
+            Commands::Latency { endpoints, attempts, tls, http, timeout } => {
+                let options = latency::Options {
+                    attempts: *attempts,
+                    tls: *tls,
+                    http: *http,
+                    timeout: Duration::try_from_secs_f64(*timeout)
+                        .with_context(|| format!("invalid timeout {}", timeout))?,
+                };
+
+                CommandResult::Latency(
+                    latency::measure_all(endpoints, &options).await
+                        .with_context(|| "measuring the endpoint latency failed")?
+                )
+            },
+            Commands::Proxy => CommandResult::Proxy(
+                proxy::proxies().await
+                    .with_context(|| "looking up the proxy configuration failed")?
+            ),
+            Commands::Locale => CommandResult::Locale(
+                locale::locale().await
+                    .with_context(|| "looking up the locale failed")?
+            ),
+            Commands::Country { query } => CommandResult::Country(
+                country::countries(query).await
+                    .with_context(|| format!("looking up country {} failed", query))?
             ),
-            Commands::Ips {only} => match only {
-                Some(network::IpCategory::Public) => {
-                    let public_ip = network::query_public_ip(
-                        network::OPENDNS_SERVER_HOST,
-                        network::DNS_DEFAULT_PORT,
-                    ).await
-                        .with_context(|| {
-                            format!(
-                                "looking up public ip failed; reason: querying dns server {} on port {} failed",
-                                network::OPENDNS_SERVER_HOST,
-                                network::DNS_DEFAULT_PORT,
-                            )
-                        })?;
-                    CommandResult::Ips(vec![network::Ip{
-                        category: network::IpCategory::Public,
-                        address: public_ip,
-                    }])
-                },
-                Some(network::IpCategory::Local) => {
-                    let local_ip = local_ip_address::local_ip()
-                        .with_context(|| "looking up local ip failed; reason: querying local ip address failed")?;
-
-                    CommandResult::Ips(vec![network::Ip{
-                        category: network::IpCategory::Local,
-                        address: local_ip,
-                    }])
-                },
-                Some(network::IpCategory::Any) | None => {
-                    let public_ip = network::query_public_ip(
-                        network::OPENDNS_SERVER_HOST,
-                        network::DNS_DEFAULT_PORT,
-                    ).await
-                        .with_context(|| {
-                            format!(
-                                "listing ips failed; reason: querying dns server {} on port {} failed",
-                                network::OPENDNS_SERVER_HOST,
-                                network::DNS_DEFAULT_PORT,
-                            )
-                        })?;
-
-                    let local_ip = local_ip_address::local_ip()
-                        .with_context(|| "listing ips failed; reason: querying local ip address failed")?;
-
-                    CommandResult::Ips(vec![
-                        network::Ip{
+            Commands::Ips {only, dns_transport, reverse, geo, geo_db} => {
+                let mut ips = match only {
+                    Some(network::IpCategory::Public) => {
+                        let dns_server = network::DnsServer::opendns(*dns_transport);
+                        let public_ip = network::query_public_ip(&dns_server).await
+                            .with_context(|| {
+                                format!(
+                                    "looking up public ip failed; reason: querying dns server {} failed",
+                                    dns_server,
+                                )
+                            })?;
+                        vec![network::Ip{
                             category: network::IpCategory::Public,
                             address: public_ip,
-                        },
-                        network::Ip{
+                            ptr: None,
+                            country: None,
+                        }]
+                    },
+                    Some(network::IpCategory::Local) => {
+                        let local_ip = local_ip_address::local_ip()
+                            .with_context(|| "looking up local ip failed; reason: querying local ip address failed")?;
+
+                        vec![network::Ip{
                             category: network::IpCategory::Local,
                             address: local_ip,
-                        },
-                    ])
+                            ptr: None,
+                            country: None,
+                        }]
+                    },
+                    Some(network::IpCategory::Any) | None => {
+                        let dns_server = network::DnsServer::opendns(*dns_transport);
+                        let public_ip = network::query_public_ip(&dns_server).await
+                            .with_context(|| {
+                                format!(
+                                    "listing ips failed; reason: querying dns server {} failed",
+                                    dns_server,
+                                )
+                            })?;
+
+                        let local_ip = local_ip_address::local_ip()
+                            .with_context(|| "listing ips failed; reason: querying local ip address failed")?;
+
+                        vec![
+                            network::Ip{
+                                category: network::IpCategory::Public,
+                                address: public_ip,
+                                ptr: None,
+                                country: None,
+                            },
+                            network::Ip{
+                                category: network::IpCategory::Local,
+                                address: local_ip,
+                                ptr: None,
+                                country: None,
+                            },
+                        ]
+                    }
+                };
+
+                if *reverse {
+                    network::attach_ip_ptrs(&mut ips).await
+                        .with_context(|| "looking up the reverse dns names failed")?;
                 }
+
+                if *geo {
+                    network::attach_ip_countries(&mut ips, geo_db).await
+                        .with_context(|| "looking up the country of the public ip failed")?;
+                }
+
+                CommandResult::Ips(ips)
             }
         };
 
@@ -242,6 +661,12 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// 启用 human-panic，其宏内部仍引用了已弃用的 `PanicInfo` 别名
+#[allow(deprecated)]
+fn setup_human_panic() {
+    setup_panic!();
+}
+
 /// CommandResult保存命令的结果
 /// 这是为了方便分解命令执行，
 /// 并允许将结果序列化为所需的输出格式
@@ -249,16 +674,32 @@ enum CommandResult {
     Date(datetime::Date),
     Time(datetime::Time),
     Datetime(datetime::Datetime),
-    Dns(Vec<String>),
+    Dns(network::DnsConfig),
+    DnsCheck(Vec<network::DnsServerCheck>),
+    Resolve(network::Resolution),
+    Hosts(hosts::Hosts),
     Hostname(output::Named),
     Username(output::Named),
     DeviceName(output::Named),
     Os(output::Named),
     Architecture(output::Named),
     Cpu(system::Cpu),
+    Load(system::Load),
     Ram(system::Ram),
+    Processes(Vec<processes::Process>),
+    Sensors(Vec<sensors::Sensor>),
+    Battery(battery::PowerSupply),
     Disks(Vec<storage::DiskInfo>),
     Interfaces(Vec<network::Interface>),
+    Wifi(Vec<wireless::WirelessLink>),
+    Routes(network::Routes),
+    Neighbors(Vec<network::Neighbor>),
+    Ports(Vec<network::ListeningSocket>),
+    Connectivity(connectivity::Connectivity),
+    Latency(Vec<latency::LatencyReport>),
+    Proxy(Vec<proxy::ProxySetting>),
+    Locale(locale::Locale),
+    Country(Vec<country::CountryDetails>),
     Ips(Vec<network::Ip>),
 }
 
@@ -269,16 +710,33 @@ impl Display for CommandResult {
             CommandResult::Date(date) => date.fmt(f),
             CommandResult::Time(time) => time.fmt(f),
             CommandResult::Datetime(datetime) => datetime.fmt(f),
-            CommandResult::Dns(dns) => {
-                write!(f, "{}", dns.join("\n"))
+            CommandResult::Dns(dns) => dns.fmt(f),
+            CommandResult::DnsCheck(checks) => {
+                let checks = checks.iter().map(ToString::to_string).collect::<Vec<String>>();
+                write!(f, "{}", checks.join("\n"))
             },
+            CommandResult::Resolve(resolution) => resolution.fmt(f),
+            CommandResult::Hosts(hosts) => hosts.fmt(f),
             CommandResult::Hostname(hostname) => hostname.fmt(f),
             CommandResult::Username(username) => username.fmt(f),
             CommandResult::DeviceName(device_name) => device_name.fmt(f),
             CommandResult::Os(os) => os.fmt(f),
             CommandResult::Architecture(architecture) => architecture.fmt(f),
             CommandResult::Cpu(cpu) => cpu.fmt(f),
+            CommandResult::Load(load) => load.fmt(f),
             CommandResult::Ram(ram) => ram.fmt(f),
+            CommandResult::Processes(processes) => {
+                let processes = processes.iter().map(ToString::to_string).collect::<Vec<String>>();
+                write!(f, "{}", processes.join("\n"))
+            },
+            CommandResult::Sensors(sensors) => {
+                if sensors.is_empty() {
+                    return write!(f, "no sensors found");
+                }
+                let sensors = sensors.iter().map(ToString::to_string).collect::<Vec<String>>();
+                write!(f, "{}", sensors.join("\n"))
+            },
+            CommandResult::Battery(power_supply) => power_supply.fmt(f),
             CommandResult::Disks(disks) =>  {
                 write!(
                     f,
@@ -301,6 +759,39 @@ impl Display for CommandResult {
                         .join("\n")
                 )
             },
+            CommandResult::Wifi(links) => {
+                if links.is_empty() {
+                    return write!(f, "no wireless interfaces");
+                }
+                let links = links.iter().map(ToString::to_string).collect::<Vec<String>>();
+                write!(f, "{}", links.join("\n"))
+            },
+            CommandResult::Routes(routes) => routes.fmt(f),
+            CommandResult::Connectivity(connectivity) => connectivity.fmt(f),
+            CommandResult::Proxy(settings) => {
+                if settings.is_empty() {
+                    return write!(f, "no proxy configured");
+                }
+                let settings = settings.iter().map(ToString::to_string).collect::<Vec<String>>();
+                write!(f, "{}", settings.join("\n"))
+            },
+            CommandResult::Locale(locale) => locale.fmt(f),
+            CommandResult::Country(countries) => {
+                let countries = countries.iter().map(ToString::to_string).collect::<Vec<String>>();
+                write!(f, "{}", countries.join("\n"))
+            },
+            CommandResult::Latency(reports) => {
+                let reports = reports.iter().map(ToString::to_string).collect::<Vec<String>>();
+                write!(f, "{}", reports.join("\n"))
+            },
+            CommandResult::Neighbors(neighbors) => {
+                let neighbors = neighbors.iter().map(ToString::to_string).collect::<Vec<String>>();
+                write!(f, "{}", neighbors.join("\n"))
+            },
+            CommandResult::Ports(sockets) => {
+                let sockets = sockets.iter().map(ToString::to_string).collect::<Vec<String>>();
+                write!(f, "{}", sockets.join("\n"))
+            },
             CommandResult::Ips(ips) => {
                 let ips = ips.iter().map(ToString::to_string).collect::<Vec<String>>();
                 write!(f,"{}", ips.join("\n"))
@@ -318,15 +809,31 @@ impl serde::Serialize for CommandResult {
             CommandResult::Time(time) => time.serialize(serializer),
             CommandResult::Datetime(datetime) => datetime.serialize(serializer),
             CommandResult::Dns(dns) => dns.serialize(serializer),
+            CommandResult::DnsCheck(checks) => checks.serialize(serializer),
+            CommandResult::Resolve(resolution) => resolution.serialize(serializer),
+            CommandResult::Hosts(hosts) => hosts.serialize(serializer),
             CommandResult::Hostname(hostname) => hostname.serialize(serializer),
             CommandResult::Username(username) => username.serialize(serializer),
             CommandResult::DeviceName(device_name) => device_name.serialize(serializer),
             CommandResult::Os(os) => os.serialize(serializer),
             CommandResult::Architecture(architecture) => architecture.serialize(serializer),
             CommandResult::Cpu(cpu) => cpu.serialize(serializer),
+            CommandResult::Load(load) => load.serialize(serializer),
             CommandResult::Ram(ram) => ram.serialize(serializer),
+            CommandResult::Processes(processes) => processes.serialize(serializer),
+            CommandResult::Sensors(sensors) => sensors.serialize(serializer),
+            CommandResult::Battery(power_supply) => power_supply.serialize(serializer),
             CommandResult::Disks(disks) => disks.serialize(serializer),
             CommandResult::Interfaces(interfaces) => interfaces.serialize(serializer),
+            CommandResult::Wifi(links) => links.serialize(serializer),
+            CommandResult::Routes(routes) => routes.serialize(serializer),
+            CommandResult::Neighbors(neighbors) => neighbors.serialize(serializer),
+            CommandResult::Ports(sockets) => sockets.serialize(serializer),
+            CommandResult::Connectivity(connectivity) => connectivity.serialize(serializer),
+            CommandResult::Latency(reports) => reports.serialize(serializer),
+            CommandResult::Proxy(settings) => settings.serialize(serializer),
+            CommandResult::Locale(locale) => locale.serialize(serializer),
+            CommandResult::Country(countries) => countries.serialize(serializer),
             CommandResult::Ips(ips) => ips.serialize(serializer),
         }
     }
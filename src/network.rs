@@ -1,24 +1,38 @@
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
-use std::net::{IpAddr, Ipv4Addr, SocketAddr};
-use anyhow::Result;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use anyhow::{bail, Context, Result};
 use clap::ValueEnum;
+use colored::*;
 use serde::{Deserialize, Serialize};
+use sysinfo::{Pid, PidExt, ProcessExt, ProcessRefreshKind, RefreshKind, System, SystemExt};
 use tokio::task::spawn_blocking;
 use trust_dns_resolver::{system_conf, TokioAsyncResolver, TokioHandle};
 use trust_dns_resolver::config::{NameServerConfig, Protocol, ResolverConfig, ResolverOpts};
+use trust_dns_resolver::error::ResolveErrorKind;
+use tokio::net::UdpSocket;
+use trust_dns_resolver::proto::op::{Message, MessageType, Query, ResponseCode};
+use trust_dns_resolver::proto::rr::{Name, RecordType};
+use trust_dns_resolver::proto::serialize::binary::BinEncodable;
+use crate::format::human_readable_size;
+use crate::geoip;
+use crate::hosts;
+use crate::wireless::{self, WirelessLink};
 
 
-/// 列出系统配置中的DNS服务器。
-/// DNS服务器以IP地址列表的形式返回。
-/// 完成DNS服务器重复数据删除。
+/// 列出系统配置中的DNS服务器及解析器选项。
 /// DNS服务器按照系统配置中定义的顺序返回。
+/// 完成DNS服务器重复数据删除。
 ///
 /// # Returns
 ///
-/// The DNS servers:
-///   * The DNS servers are returned as a list of IP addresses.
+/// The resolver configuration:
+///   * The DNS servers are returned with their address, port and protocols.
 ///   * The DNS servers are deduplicated.
 ///   * The DNS servers are returned in the order they are defined in the system configuration.
+///   * The search domains and resolver options are returned separately.
 ///
 /// # Errors
 ///
@@ -27,24 +41,117 @@ use trust_dns_resolver::config::{NameServerConfig, Protocol, ResolverConfig, Res
 /// # Examples
 ///
 /// ```
-/// let dns_servers = ip::list_dns_servers().unwrap();
-/// println!("dns servers: {:?}", dns_servers);
-pub async fn list_dns_servers() -> Result<Vec<String>> {
-    let (conf, _) = system_conf::read_system_conf()?;
-    let mut nameservers = conf
-        .name_servers()
-        .iter()
-        .map(|ns| {
-            ns.socket_addr
-                .to_string()
-                .splitn(2,':')
-                .next()
-                .unwrap()
-                .to_owned()
-        }).collect::<Vec<_>>();
+/// let dns_config = network::list_dns_servers().await.unwrap();
+/// println!("dns servers: {:?}", dns_config.nameservers);
+/// ```
+pub async fn list_dns_servers() -> Result<DnsConfig> {
+    let (conf, opts) = system_conf::read_system_conf()?;
+    Ok(DnsConfig::new(&conf, &opts))
+}
+
+/// 系统的DNS解析器配置
+#[derive(Serialize, Debug)]
+pub struct DnsConfig {
+    /// DNS服务器，按使用顺序排列
+    pub nameservers: Vec<NameServer>,
+
+    /// 搜索域，按使用顺序排列
+    pub search_domains: Vec<String>,
+
+    /// 解析器选项
+    pub options: DnsOptions,
+}
+
+impl DnsConfig {
+    fn new(conf: &ResolverConfig, opts: &ResolverOpts) -> Self {
+        // trust-dns 为 resolv.conf 中的每个服务器各生成一条UDP和一条TCP配置，按地址和端口合并为一条
+        let mut nameservers: Vec<NameServer> = Vec::new();
+        for ns in conf.name_servers() {
+            let (address, port) = (ns.socket_addr.ip(), ns.socket_addr.port());
+            let protocol = ns.protocol.to_string();
+            match nameservers.iter_mut().find(|nameserver| nameserver.address == address && nameserver.port == port) {
+                Some(nameserver) if nameserver.protocols.contains(&protocol) => {}
+                Some(nameserver) => nameserver.protocols.push(protocol),
+                None => nameservers.push(NameServer { address, port, protocols: vec![protocol] }),
+            }
+        }
+
+        // resolv.conf 中 search 会覆盖 domain，没有 search 时 domain 即为唯一的搜索域
+        let search_domains = if conf.search().is_empty() {
+            conf.domain().map(ToString::to_string).into_iter().collect()
+        } else {
+            conf.search().iter().map(ToString::to_string).collect()
+        };
+
+        DnsConfig {
+            nameservers,
+            search_domains,
+            options: DnsOptions {
+                ndots: opts.ndots,
+                timeout_seconds: opts.timeout.as_secs_f64(),
+                attempts: opts.attempts,
+            },
+        }
+    }
+}
+
+impl Display for DnsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for nameserver in &self.nameservers {
+            writeln!(f, "nameserver\t{}", nameserver)?;
+        }
+        if !self.search_domains.is_empty() {
+            writeln!(f, "search\t\t{}", self.search_domains.join(" "))?;
+        }
+        write!(f, "options\t\t{}", self.options)
+    }
+}
+
+/// 一个DNS服务器
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct NameServer {
+    /// DNS服务器的IP地址
+    pub address: IpAddr,
+
+    /// DNS服务器的端口
+    pub port: u16,
+
+    /// 与DNS服务器通信可以使用的协议，按系统配置中的顺序排列
+    pub protocols: Vec<String>,
+}
+
+impl Display for NameServer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}",
+            SocketAddr::new(self.address, self.port).to_string().bold(),
+            self.protocols.join("/"),
+        )
+    }
+}
+
+/// 解析器选项，与 resolv.conf 中的 `options` 对应
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+pub struct DnsOptions {
+    /// 名称中至少包含多少个点时才直接查询，而不先追加搜索域
+    pub ndots: usize,
 
-    nameservers.dedup();
-    Ok(nameservers)
+    /// 单次查询的超时时间，单位为秒
+    pub timeout_seconds: f64,
+
+    /// 查询失败后的重试次数
+    pub attempts: usize,
+}
+
+impl Display for DnsOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ndots:{} timeout:{} attempts:{}",
+            self.ndots, self.timeout_seconds, self.attempts,
+        )
+    }
 }
 
 ///列出系统的网络接口。
@@ -65,13 +172,17 @@ pub async fn list_dns_servers() -> Result<Vec<String>> {
 /// println!("interfaces: {:?}", interfaces);
 /// ```
 pub async fn interfaces()  -> Result<Vec<Interface>> {
-    spawn_blocking(||  get_if_addrs::get_if_addrs())
+    spawn_blocking(get_if_addrs::get_if_addrs)
         .await??
         .into_iter()
         .try_fold(Vec::new(), |mut acc, i|  {
             acc.push(Interface{
                 name: i.name.clone(),
                 ip: i.ip().to_string(),
+                stats: None,
+                rate: None,
+                ptr: None,
+                wireless: None,
             });
             Ok(acc)
         })
@@ -81,16 +192,204 @@ pub async fn interfaces()  -> Result<Vec<Interface>> {
 #[derive(Serialize)]
 pub struct Interface {
     ///网络接口名称。
-    name: String,
+    pub name: String,
 
     ///网口的IP地址。
-    ip: String,
+    pub ip: String,
+
+    ///网口的流量计数器，仅在请求时填充。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stats: Option<InterfaceStats>,
+
+    ///网口的当前吞吐量，仅在请求时填充。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<InterfaceRate>,
+
+    ///网口IP地址的反向解析名称，仅在请求时填充。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ptr: Option<String>,
+
+    ///无线网口的连接详情，有线网口为空。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wireless: Option<WirelessLink>,
 }
 
 impl Display for Interface {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}\t{}", self.name, self.ip)
+        write!(f, "{}\t{}", self.name, self.ip)?;
+        if let Some(stats) = &self.stats {
+            write!(f, "\t{}", stats)?;
+        }
+        if let Some(rate) = &self.rate {
+            write!(f, "\t{}", rate)?;
+        }
+        if let Some(ptr) = &self.ptr {
+            write!(f, "\t{}", ptr.bright_white())?;
+        }
+        if let Some(wireless) = &self.wireless {
+            match &wireless.ssid {
+                Some(ssid) => write!(f, "\twifi {}", ssid.cyan())?,
+                None => write!(f, "\twifi {}", "not connected".yellow())?,
+            }
+            if let Some(signal) = wireless.signal_dbm {
+                write!(f, " {} dBm", signal)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 为无线网口附加连接详情。无线信息只是补充，查询失败时不附加任何信息。
+pub async fn attach_wireless(interfaces: &mut [Interface]) {
+    let Ok(links) = wireless::links().await else {
+        return;
+    };
+    for interface in interfaces.iter_mut() {
+        interface.wireless = links.iter().find(|link| link.interface == interface.name).cloned();
+    }
+}
+
+/// 内核网口计数器文件
+pub const PROC_NET_DEV: &str = "/proc/net/dev";
+
+/// 网口的收发计数器，取自 `/proc/net/dev`
+#[derive(Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InterfaceStats {
+    pub rx_bytes: u64,
+    pub rx_packets: u64,
+    pub rx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_bytes: u64,
+    pub tx_packets: u64,
+    pub tx_errors: u64,
+    pub tx_dropped: u64,
+}
+
+impl Display for InterfaceStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "rx {} ({} packets, {} errors, {} dropped)\ttx {} ({} packets, {} errors, {} dropped)",
+            human_readable_size(self.rx_bytes).green(),
+            self.rx_packets,
+            colored_count(self.rx_errors),
+            colored_count(self.rx_dropped),
+            human_readable_size(self.tx_bytes).cyan(),
+            self.tx_packets,
+            colored_count(self.tx_errors),
+            colored_count(self.tx_dropped),
+        )
+    }
+}
+
+/// 错误和丢包计数非零时标红
+fn colored_count(count: u64) -> ColoredString {
+    if count > 0 {
+        count.to_string().red()
+    } else {
+        count.to_string().normal()
+    }
+}
+
+/// 网口在一个采样间隔内的平均吞吐量
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InterfaceRate {
+    pub rx_bytes_per_second: u64,
+    pub tx_bytes_per_second: u64,
+}
+
+impl InterfaceRate {
+    /// 根据两次采样之间的差值计算吞吐量。
+    /// 计数器回绕或网口重置时按零处理。
+    pub fn between(before: &InterfaceStats, after: &InterfaceStats, interval: Duration) -> Self {
+        let seconds = interval.as_secs_f64().max(f64::EPSILON);
+        let per_second = |before: u64, after: u64| (after.saturating_sub(before) as f64 / seconds).round() as u64;
+
+        InterfaceRate {
+            rx_bytes_per_second: per_second(before.rx_bytes, after.rx_bytes),
+            tx_bytes_per_second: per_second(before.tx_bytes, after.tx_bytes),
+        }
+    }
+}
+
+impl Display for InterfaceRate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "rx {}/s\ttx {}/s",
+            human_readable_size(self.rx_bytes_per_second).green().bold(),
+            human_readable_size(self.tx_bytes_per_second).cyan().bold(),
+        )
+    }
+}
+
+/// 解析 `/proc/net/dev` 的内容，返回按网口名称索引的计数器。
+///
+/// # Errors
+///
+/// 如果某一行的计数器不是合法的数字。
+pub fn parse_net_dev(contents: &str) -> Result<HashMap<String, InterfaceStats>> {
+    contents
+        .lines()
+        // 前两行是表头
+        .skip(2)
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, counters)| {
+            let counters = counters
+                .split_whitespace()
+                .map(str::parse::<u64>)
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .with_context(|| format!("invalid counters for interface {}", name.trim()))?;
+
+            if counters.len() < 12 {
+                bail!("interface {} has {} counters, expected at least 12", name.trim(), counters.len());
+            }
+
+            Ok((name.trim().to_string(), InterfaceStats {
+                rx_bytes: counters[0],
+                rx_packets: counters[1],
+                rx_errors: counters[2],
+                rx_dropped: counters[3],
+                tx_bytes: counters[8],
+                tx_packets: counters[9],
+                tx_errors: counters[10],
+                tx_dropped: counters[11],
+            }))
+        })
+        .collect()
+}
+
+/// 读取所有网口的收发计数器
+pub async fn interface_stats() -> Result<HashMap<String, InterfaceStats>> {
+    let contents = spawn_blocking(|| std::fs::read_to_string(PROC_NET_DEV))
+        .await?
+        .with_context(|| format!("reading {} failed", PROC_NET_DEV))?;
+    parse_net_dev(&contents)
+}
+
+/// 为网口附加收发计数器。
+/// 如果提供了 `rate_interval`，则间隔一段时间再次采样并计算当前吞吐量。
+pub async fn attach_interface_stats(interfaces: &mut [Interface], rate_interval: Option<Duration>) -> Result<()> {
+    let before = interface_stats().await?;
+
+    let after = match rate_interval {
+        Some(interval) => {
+            tokio::time::sleep(interval).await;
+            let after = interface_stats().await?;
+            for interface in interfaces.iter_mut() {
+                if let (Some(b), Some(a)) = (before.get(&interface.name), after.get(&interface.name)) {
+                    interface.rate = Some(InterfaceRate::between(b, a, interval));
+                }
+            }
+            after
+        }
+        None => before,
+    };
+
+    for interface in interfaces.iter_mut() {
+        interface.stats = after.get(&interface.name).copied();
     }
+    Ok(())
 }
 
 /// 保存IP地址的类别。类别可以是公共的、本地的或任意的。
@@ -121,19 +420,148 @@ impl Display for IpCategory {
 /// 此常量作为默认值用于查询公共IP地址
 pub const DNS_DEFAULT_PORT:u16 = 53;
 
+/// DNS-over-TLS 的默认端口
+pub const DNS_OVER_TLS_DEFAULT_PORT:u16 = 853;
+
+/// DNS-over-HTTPS 的默认端口
+pub const DNS_OVER_HTTPS_DEFAULT_PORT:u16 = 443;
+
 
 /// openns服务器主机
 ///
 /// 此常量作为默认值用于查询公共IP地址
 pub const OPENDNS_SERVER_HOST:&str = "208.67.222.222";
 
+/// OpenDNS 服务器的TLS证书名称
+///
+/// 通过 DNS-over-TLS 查询公共IP地址时用于校验证书
+pub const OPENDNS_TLS_NAME:&str = "dns.opendns.com";
+
+/// OpenDNS 的 DNS-over-HTTPS 服务器主机
+///
+/// 通过 DNS-over-HTTPS 查询公共IP地址时使用
+pub const OPENDNS_DOH_HOST:&str = "146.112.41.2";
+
+/// OpenDNS 的 DNS-over-HTTPS 服务器的TLS证书名称
+pub const OPENDNS_DOH_TLS_NAME:&str = "doh.opendns.com";
+
+/// 与DNS服务器通信使用的传输协议
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum DnsTransport {
+    #[clap(name = "udp")]
+    Udp,
+
+    #[clap(name = "tcp")]
+    Tcp,
+
+    #[clap(name = "tls")]
+    Tls,
+
+    #[clap(name = "https")]
+    Https,
+}
+
+impl DnsTransport {
+    /// 返回该传输协议的默认端口
+    pub fn default_port(self) -> u16 {
+        match self {
+            DnsTransport::Udp | DnsTransport::Tcp => DNS_DEFAULT_PORT,
+            DnsTransport::Tls => DNS_OVER_TLS_DEFAULT_PORT,
+            DnsTransport::Https => DNS_OVER_HTTPS_DEFAULT_PORT,
+        }
+    }
+
+    /// 加密的传输协议需要TLS证书名称来校验服务器
+    pub fn is_encrypted(self) -> bool {
+        matches!(self, DnsTransport::Tls | DnsTransport::Https)
+    }
+}
+
+impl From<DnsTransport> for Protocol {
+    fn from(transport: DnsTransport) -> Self {
+        match transport {
+            DnsTransport::Udp => Protocol::Udp,
+            DnsTransport::Tcp => Protocol::Tcp,
+            DnsTransport::Tls => Protocol::Tls,
+            DnsTransport::Https => Protocol::Https,
+        }
+    }
+}
+
+impl Display for DnsTransport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DnsTransport::Udp => write!(f, "udp"),
+            DnsTransport::Tcp => write!(f, "tcp"),
+            DnsTransport::Tls => write!(f, "tls"),
+            DnsTransport::Https => write!(f, "https"),
+        }
+    }
+}
+
+/// 要查询的DNS服务器及其传输协议
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DnsServer {
+    /// DNS服务器的地址
+    pub address: SocketAddr,
+
+    /// 与DNS服务器通信使用的传输协议
+    pub transport: DnsTransport,
+
+    /// 校验TLS证书时使用的服务器名称，加密传输时必须提供
+    pub tls_name: Option<String>,
+}
+
+impl DnsServer {
+    /// 使用传输协议的默认端口创建DNS服务器
+    pub fn new(address: IpAddr, transport: DnsTransport) -> Self {
+        DnsServer {
+            address: SocketAddr::new(address, transport.default_port()),
+            transport,
+            tls_name: None,
+        }
+    }
+
+    /// 设置DNS服务器的端口，为空时保留传输协议的默认端口
+    pub fn with_port(mut self, port: Option<u16>) -> Self {
+        if let Some(port) = port {
+            self.address.set_port(port);
+        }
+        self
+    }
+
+    /// 设置校验TLS证书时使用的服务器名称
+    pub fn with_tls_name(mut self, tls_name: Option<String>) -> Self {
+        self.tls_name = tls_name;
+        self
+    }
+
+    /// 返回用于查询公共IP地址的 OpenDNS 服务器
+    pub fn opendns(transport: DnsTransport) -> Self {
+        let (host, tls_name) = match transport {
+            DnsTransport::Udp | DnsTransport::Tcp => (OPENDNS_SERVER_HOST, None),
+            DnsTransport::Tls => (OPENDNS_SERVER_HOST, Some(OPENDNS_TLS_NAME)),
+            DnsTransport::Https => (OPENDNS_DOH_HOST, Some(OPENDNS_DOH_TLS_NAME)),
+        };
+
+        DnsServer::new(host.parse().expect("OpenDNS hosts are valid IP addresses"), transport)
+            .with_tls_name(tls_name.map(str::to_string))
+    }
+}
+
+impl Display for DnsServer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} over {}", self.address, self.transport)
+    }
+}
+
 /// 从提供的dns服务器上查询公网IP地址
 /// 只返回IPv4地址。
 ///
 /// # Arguments
 ///
-/// * `dns_server_host` - 要查询公网IP地址的DNS服务器主机
-/// * `dns_server_port` - 查询公网IP地址的DNS服务器端口
+/// * `dns_server` - 要查询公网IP地址的DNS服务器及其传输协议
 ///
 /// # Returns
 ///
@@ -141,30 +569,21 @@ pub const OPENDNS_SERVER_HOST:&str = "208.67.222.222";
 ///
 /// # Errors
 ///
-/// 如果无法解析DNS服务器主机，或者无法查询到DNS服务器。
+/// 如果无法创建解析器，或者无法查询到DNS服务器。
 ///
 /// # Examples
 ///
 /// ```
 /// use std::net::IpAddr;
 ///
-/// let public_ip = ip::query_public_ip(ip::OPENDNS_SERVER_HOST, 53).unwrap();
+/// let dns_server = network::DnsServer::opendns(network::DnsTransport::Tls);
+/// let public_ip = network::query_public_ip(&dns_server).await.unwrap();
 /// println!("public ip: {}", public_ip);
 /// ```
-pub async fn query_public_ip(dns_server_host:&str, dns_server_port:u16) -> Result<IpAddr> {
-
-    // 设置解析器配置
-    let dns_server_addr  = SocketAddr::new(dns_server_host.parse()?, dns_server_port);
-    let nameserver_config = NameServerConfig::new(dns_server_addr, Protocol::Udp);
-    let resolver_config = ResolverConfig::from_parts(None,vec![], vec![nameserver_config]);
-
-
-    let mut resolver_opts = ResolverOpts::default();
-    resolver_opts.ndots = 1;
-    resolver_opts.timeout = std::time::Duration::from_secs(5);
+pub async fn query_public_ip(dns_server: &DnsServer) -> Result<IpAddr> {
 
     //创建解析器
-    let resolver = TokioAsyncResolver::new(resolver_config, resolver_opts,TokioHandle)?;
+    let resolver = server_resolver(dns_server)?;
 
     //向OpenDNS服务器查询公网IP地址
     let ipv4_response = resolver.ipv4_lookup("myip.opendns.com").await?;
@@ -175,6 +594,48 @@ pub async fn query_public_ip(dns_server_host:&str, dns_server_port:u16) -> Resul
 
 }
 
+/// 创建只向指定DNS服务器查询的解析器
+fn server_resolver(dns_server: &DnsServer) -> Result<TokioAsyncResolver> {
+    if dns_server.transport.is_encrypted() && dns_server.tls_name.is_none() {
+        bail!("the {} transport needs a TLS name to verify {}", dns_server.transport, dns_server.address);
+    }
+
+    let mut nameserver_config = NameServerConfig::new(dns_server.address, dns_server.transport.into());
+    nameserver_config.tls_dns_name = dns_server.tls_name.clone();
+    let resolver_config = ResolverConfig::from_parts(None,vec![], vec![nameserver_config]);
+
+    let mut resolver_opts = ResolverOpts::default();
+    resolver_opts.ndots = 1;
+    resolver_opts.timeout = Duration::from_secs(5);
+    // 答案必须来自指定的服务器，而不是本机的 /etc/hosts
+    resolver_opts.use_hosts_file = false;
+
+    Ok(TokioAsyncResolver::new(resolver_config, resolver_opts,TokioHandle)?)
+}
+
+/// 创建使用系统配置的DNS服务器的解析器。
+/// UDP 即系统解析器的默认行为；TCP 会只保留系统配置中的TCP服务器。
+/// 系统配置中的服务器没有TLS证书名称，因此不支持加密传输。
+fn system_resolver(transport: DnsTransport) -> Result<TokioAsyncResolver> {
+    match transport {
+        DnsTransport::Udp => Ok(TokioAsyncResolver::tokio_from_system_conf()?),
+        DnsTransport::Tcp => {
+            let (conf, opts) = system_conf::read_system_conf()?;
+            let nameservers: Vec<_> = conf
+                .name_servers()
+                .iter()
+                .filter(|ns| ns.protocol == Protocol::Tcp)
+                .cloned()
+                .collect();
+            let conf = ResolverConfig::from_parts(conf.domain().cloned(), conf.search().to_vec(), nameservers);
+            Ok(TokioAsyncResolver::new(conf, opts, TokioHandle)?)
+        }
+        DnsTransport::Tls | DnsTransport::Https => {
+            bail!("the {} transport needs an explicit DNS server and TLS name", transport)
+        }
+    }
+}
+
 /// 分类IP地址
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Ip {
@@ -183,10 +644,1871 @@ pub struct Ip {
 
     /// ip 类别
     pub category: IpCategory,
+
+    /// ip 地址的反向解析名称，仅在请求时填充
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ptr: Option<String>,
+
+    /// 公共IP地址所属的国家，仅在请求时填充
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub country: Option<geoip::Country>,
 }
 
 impl Display for Ip {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}\t{}", self.category, self.address)
+        write!(f, "{}\t{}", self.category, self.address)?;
+        if let Some(ptr) = &self.ptr {
+            write!(f, "\t{}", ptr.bright_white())?;
+        }
+        if let Some(country) = &self.country {
+            write!(f, "\t{}", country)?;
+        }
+        Ok(())
+    }
+}
+
+/// 使用 `resolver` 并发地反向解析IP地址。
+/// 返回的名称与输入的地址一一对应，没有PTR记录或查询失败的地址对应 `None`。
+pub async fn reverse_lookup_all(resolver: &TokioAsyncResolver, addresses: &[IpAddr]) -> Result<Vec<Option<String>>> {
+    let lookups = addresses
+        .iter()
+        .map(|&address| {
+            let resolver = resolver.clone();
+            tokio::spawn(async move {
+                resolver
+                    .reverse_lookup(address)
+                    .await
+                    .ok()
+                    .and_then(|names| names.iter().next().map(|name| name.to_string().trim_end_matches('.').to_string()))
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut names = Vec::with_capacity(lookups.len());
+    for lookup in lookups {
+        names.push(lookup.await?);
+    }
+    Ok(names)
+}
+
+/// 使用系统解析器为IP地址附加反向解析名称
+///
+/// # Errors
+///
+/// 如果无法读取系统的解析器配置。
+pub async fn attach_ip_ptrs(ips: &mut [Ip]) -> Result<()> {
+    let resolver = TokioAsyncResolver::tokio_from_system_conf()?;
+    attach_ip_ptrs_with(&resolver, ips).await
+}
+
+async fn attach_ip_ptrs_with(resolver: &TokioAsyncResolver, ips: &mut [Ip]) -> Result<()> {
+    let addresses = ips.iter().map(|ip| ip.address).collect::<Vec<_>>();
+    let names = reverse_lookup_all(resolver, &addresses).await?;
+    for (ip, name) in ips.iter_mut().zip(names) {
+        ip.ptr = name;
+    }
+    Ok(())
+}
+
+/// 使用离线数据库为公共IP地址附加所属的国家
+pub async fn attach_ip_countries(ips: &mut [Ip], database: &Path) -> Result<()> {
+    let addresses = ips
+        .iter()
+        .filter(|ip| ip.category == IpCategory::Public)
+        .map(|ip| ip.address)
+        .collect::<Vec<_>>();
+    let countries = geoip::lookup_countries(database, &addresses).await?;
+    for (ip, country) in ips.iter_mut().filter(|ip| ip.category == IpCategory::Public).zip(countries) {
+        ip.country = country;
+    }
+    Ok(())
+}
+
+/// 使用系统解析器为网口的IP地址附加反向解析名称
+///
+/// # Errors
+///
+/// 如果无法读取系统的解析器配置，或网口的IP地址不合法。
+pub async fn attach_interface_ptrs(interfaces: &mut [Interface]) -> Result<()> {
+    let resolver = TokioAsyncResolver::tokio_from_system_conf()?;
+    attach_interface_ptrs_with(&resolver, interfaces).await
+}
+
+async fn attach_interface_ptrs_with(resolver: &TokioAsyncResolver, interfaces: &mut [Interface]) -> Result<()> {
+    let addresses = interfaces
+        .iter()
+        .map(|interface| interface.ip.parse::<IpAddr>())
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    let names = reverse_lookup_all(resolver, &addresses).await?;
+    for (interface, name) in interfaces.iter_mut().zip(names) {
+        interface.ptr = name;
+    }
+    Ok(())
+}
+
+/// 内核IPv4路由表文件
+pub const PROC_NET_ROUTE: &str = "/proc/net/route";
+
+/// 内核IPv6路由表文件
+pub const PROC_NET_IPV6_ROUTE: &str = "/proc/net/ipv6_route";
+
+const RTF_UP: u32 = 0x0001;
+const RTF_GATEWAY: u32 = 0x0002;
+const RTF_HOST: u32 = 0x0004;
+const RTF_DYNAMIC: u32 = 0x0010;
+const RTF_MODIFIED: u32 = 0x0020;
+const RTF_REJECT: u32 = 0x0200;
+
+/// 路由表中的一条路由
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct Route {
+    /// 目的网络
+    pub destination: IpAddr,
+
+    /// 目的网络的前缀长度
+    pub prefix_length: u8,
+
+    /// 下一跳网关，直连路由没有网关
+    pub gateway: Option<IpAddr>,
+
+    /// 出口网络接口
+    pub interface: String,
+
+    /// 路由的优先级，数值越小越优先
+    pub metric: u32,
+
+    /// 路由标志，与 `route -n` 的写法相同
+    pub flags: String,
+
+    /// 是否为默认路由
+    pub default: bool,
+}
+
+impl Route {
+    fn new(destination: IpAddr, prefix_length: u8, gateway: IpAddr, interface: &str, metric: u32, flags: u32) -> Self {
+        let flag_names = [
+            (RTF_UP, 'U'),
+            (RTF_GATEWAY, 'G'),
+            (RTF_HOST, 'H'),
+            (RTF_DYNAMIC, 'D'),
+            (RTF_MODIFIED, 'M'),
+        ];
+
+        Route {
+            destination,
+            prefix_length,
+            gateway: Some(gateway).filter(|gateway| !gateway.is_unspecified()),
+            interface: interface.to_string(),
+            metric,
+            flags: flag_names
+                .iter()
+                .filter(|(flag, _)| flags & flag != 0)
+                .map(|(_, name)| *name)
+                .collect(),
+            default: prefix_length == 0 && flags & RTF_UP != 0,
+        }
+    }
+}
+
+impl Display for Route {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let destination = if self.prefix_length == 0 {
+            "default".to_string()
+        } else {
+            format!("{}/{}", self.destination, self.prefix_length)
+        };
+        let gateway = self.gateway.map_or("*".to_string(), |gateway| gateway.to_string());
+
+        let line = format!(
+            "{}\t{}\t{}\tmetric {}\t{}",
+            destination, gateway, self.interface, self.metric, self.flags
+        );
+
+        if self.default {
+            write!(f, "{}", line.green().bold())
+        } else {
+            write!(f, "{}", line)
+        }
+    }
+}
+
+/// 系统的路由表及其默认网关
+#[derive(Serialize)]
+pub struct Routes {
+    /// 默认路由的网关
+    pub gateway: Option<IpAddr>,
+
+    pub routes: Vec<Route>,
+}
+
+impl Routes {
+    fn new(routes: Vec<Route>) -> Self {
+        let mut routes = Routes { gateway: None, routes };
+        routes.gateway = routes.default_route().and_then(|route| route.gateway);
+        routes
+    }
+
+    /// 返回默认路由，优先选择带网关且度量值最小的IPv4路由
+    pub fn default_route(&self) -> Option<&Route> {
+        self.routes
+            .iter()
+            .filter(|route| route.default)
+            .min_by_key(|route| (route.destination.is_ipv6(), route.gateway.is_none(), route.metric))
+    }
+}
+
+impl Display for Routes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.gateway {
+            Some(gateway) => writeln!(f, "gateway\t{}", gateway.to_string().green().bold())?,
+            None => writeln!(f, "gateway\t{}", "none".yellow())?,
+        }
+
+        let routes = self.routes.iter().map(ToString::to_string).collect::<Vec<String>>();
+        write!(f, "{}", routes.join("\n"))
+    }
+}
+
+/// 解析 `/proc/net/route` 的内容。
+/// 地址以小端序十六进制存储。
+///
+/// # Errors
+///
+/// 如果某一行的字段缺失或不是合法的十六进制数。
+pub fn parse_route_table(contents: &str) -> Result<Vec<Route>> {
+    contents
+        .lines()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            if fields.len() < 8 {
+                bail!("malformed route entry: {}", line.trim());
+            }
+
+            let hex = |field: &str| u32::from_str_radix(field, 16)
+                .with_context(|| format!("invalid route field {:?}", field));
+            let ipv4 = |field: &str| hex(field).map(|value| IpAddr::V4(Ipv4Addr::from(u32::from_be(value))));
+
+            let mask = hex(fields[7])?;
+            Ok(Route::new(
+                ipv4(fields[1])?,
+                mask.count_ones() as u8,
+                ipv4(fields[2])?,
+                fields[0],
+                fields[6].parse().with_context(|| format!("invalid route metric {:?}", fields[6]))?,
+                hex(fields[3])?,
+            ))
+        })
+        .collect()
+}
+
+/// 解析 `/proc/net/ipv6_route` 的内容。
+/// 被拒绝的路由（例如回环接口上的不可达路由）会被忽略。
+///
+/// # Errors
+///
+/// 如果某一行的字段缺失或不是合法的十六进制数。
+pub fn parse_ipv6_route_table(contents: &str) -> Result<Vec<Route>> {
+    let mut routes = Vec::new();
+
+    for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        if fields.len() < 10 {
+            bail!("malformed ipv6 route entry: {}", line.trim());
+        }
+
+        let hex = |field: &str| u32::from_str_radix(field, 16)
+            .with_context(|| format!("invalid ipv6 route field {:?}", field));
+        let ipv6 = |field: &str| u128::from_str_radix(field, 16)
+            .map(|value| IpAddr::V6(Ipv6Addr::from(value)))
+            .with_context(|| format!("invalid ipv6 address {:?}", field));
+
+        let flags = hex(fields[8])?;
+        if flags & RTF_REJECT != 0 {
+            continue;
+        }
+
+        routes.push(Route::new(
+            ipv6(fields[0])?,
+            hex(fields[1])? as u8,
+            ipv6(fields[4])?,
+            fields[9],
+            hex(fields[5])?,
+            flags,
+        ));
+    }
+
+    Ok(routes)
+}
+
+/// 读取系统的IPv4和IPv6路由表。
+/// 系统禁用IPv6时，IPv6路由表可能不存在，此时只返回IPv4路由。
+pub async fn routes() -> Result<Routes> {
+    let (ipv4, ipv6) = spawn_blocking(|| {
+        (std::fs::read_to_string(PROC_NET_ROUTE), std::fs::read_to_string(PROC_NET_IPV6_ROUTE))
+    }).await?;
+
+    let mut routes = parse_route_table(
+        &ipv4.with_context(|| format!("reading {} failed", PROC_NET_ROUTE))?
+    )?;
+
+    match ipv6 {
+        Ok(contents) => routes.extend(parse_ipv6_route_table(&contents)?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e).with_context(|| format!("reading {} failed", PROC_NET_IPV6_ROUTE)),
     }
-}
\ No newline at end of file
+
+    Ok(Routes::new(routes))
+}
+
+/// 可查询的DNS记录类型
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum RecordKind {
+    #[clap(name = "A")]
+    A,
+
+    #[clap(name = "AAAA")]
+    Aaaa,
+
+    #[clap(name = "MX")]
+    Mx,
+
+    #[clap(name = "TXT")]
+    Txt,
+
+    #[clap(name = "CNAME")]
+    Cname,
+
+    #[clap(name = "NS")]
+    Ns,
+
+    #[clap(name = "SRV")]
+    Srv,
+
+    #[clap(name = "PTR")]
+    Ptr,
+}
+
+impl From<RecordKind> for RecordType {
+    fn from(kind: RecordKind) -> Self {
+        match kind {
+            RecordKind::A => RecordType::A,
+            RecordKind::Aaaa => RecordType::AAAA,
+            RecordKind::Mx => RecordType::MX,
+            RecordKind::Txt => RecordType::TXT,
+            RecordKind::Cname => RecordType::CNAME,
+            RecordKind::Ns => RecordType::NS,
+            RecordKind::Srv => RecordType::SRV,
+            RecordKind::Ptr => RecordType::PTR,
+        }
+    }
+}
+
+/// 一次DNS查询的结果
+#[derive(Serialize, Debug)]
+pub struct Resolution {
+    /// 查询的名称
+    pub name: String,
+
+    /// 查询的记录类型
+    pub record_type: String,
+
+    /// 应答的DNS服务器，使用系统解析器时为空
+    pub server: Option<SocketAddr>,
+
+    /// 查询使用的传输协议
+    pub transport: DnsTransport,
+
+    /// 响应码，例如 NOERROR 或 NXDOMAIN
+    pub status: String,
+
+    /// 查询耗时，单位为毫秒
+    pub latency_ms: f64,
+
+    /// 应答记录
+    pub answers: Vec<Answer>,
+
+    /// 是否由解析器按 RFC 6761 在本地应答，此时不会向服务器发送查询
+    pub answered_locally: bool,
+
+    /// 按名称服务切换配置逐个检查来源的过程，仅在请求时填充
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<Vec<ResolutionStep>>,
+}
+
+impl Display for Resolution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for answer in &self.answers {
+            writeln!(f, "{}", answer)?;
+        }
+
+        let status = if self.status == "NOERROR" {
+            self.status.green()
+        } else {
+            self.status.red()
+        };
+        write!(
+            f,
+            "{} {} {}, {} answers in {}",
+            self.name.bold(),
+            self.record_type,
+            status,
+            self.answers.len(),
+            format!("{:.1} ms", self.latency_ms).cyan(),
+        )?;
+        match self.server {
+            _ if self.answered_locally => write!(f, " answered locally as a special-use name")?,
+            Some(server) => write!(f, " via {} over {}", server, self.transport)?,
+            None => write!(f, " via system resolver over {}", self.transport)?,
+        }
+
+        if let Some(explanation) = &self.explanation {
+            for step in explanation {
+                write!(f, "\n  {}", step)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// DNS应答中的一条记录
+#[derive(Serialize, Debug)]
+pub struct Answer {
+    pub name: String,
+
+    pub record_type: String,
+
+    pub ttl: u32,
+
+    pub data: String,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\t{}\t{}\t{}", self.name, self.ttl, self.record_type, self.data.bold())
+    }
+}
+
+/// 查询DNS记录。
+/// 默认使用系统解析器及指定的传输协议；提供 `server` 时只向该服务器查询。
+/// 查询PTR记录时，IP地址会自动转换为对应的 arpa 名称。
+///
+/// # Errors
+///
+/// 如果无法创建解析器，或者查询失败（不包括名称不存在或没有对应记录）。
+pub async fn resolve(name: &str, kind: RecordKind, server: Option<&DnsServer>, transport: DnsTransport) -> Result<Resolution> {
+    let resolver = match server {
+        Some(server) => server_resolver(server)?,
+        None => system_resolver(transport)?,
+    };
+
+    let record_type = RecordType::from(kind);
+    let query = query_name(name, kind)?;
+    let answered_locally = is_loopback_name(&query);
+
+    let start = Instant::now();
+    let (status, answers) = lookup_answers(&resolver, query, record_type).await?;
+    let latency_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    Ok(Resolution {
+        name: name.to_string(),
+        record_type: record_type.to_string(),
+        server: server.map(|server| server.address),
+        transport: server.map_or(transport, |server| server.transport),
+        status: response_code_name(status),
+        latency_ms,
+        answers,
+        answered_locally,
+        explanation: None,
+    })
+}
+
+/// 名称是否属于 localhost 及其反向区域。解析器按 RFC 6761 直接应答这些名称，
+/// 即使指定了服务器也不会发出查询。
+fn is_loopback_name(name: &Name) -> bool {
+    ["127.in-addr.arpa.", "1.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.ip6.arpa."]
+        .iter()
+        .filter_map(|zone| Name::from_ascii(zone).ok())
+        .any(|zone| zone.zone_of(name))
+        || name.is_localhost()
+}
+
+/// 返回响应码在DNS协议中的名称，例如 NOERROR 或 NXDOMAIN
+pub fn response_code_name(code: ResponseCode) -> String {
+    let name = match code {
+        ResponseCode::NoError => "NOERROR",
+        ResponseCode::FormErr => "FORMERR",
+        ResponseCode::ServFail => "SERVFAIL",
+        ResponseCode::NXDomain => "NXDOMAIN",
+        ResponseCode::NotImp => "NOTIMP",
+        ResponseCode::Refused => "REFUSED",
+        ResponseCode::YXDomain => "YXDOMAIN",
+        ResponseCode::YXRRSet => "YXRRSET",
+        ResponseCode::NXRRSet => "NXRRSET",
+        ResponseCode::NotAuth => "NOTAUTH",
+        ResponseCode::NotZone => "NOTZONE",
+        ResponseCode::BADVERS => "BADVERS",
+        ResponseCode::BADSIG => "BADSIG",
+        ResponseCode::BADKEY => "BADKEY",
+        ResponseCode::BADTIME => "BADTIME",
+        ResponseCode::BADMODE => "BADMODE",
+        ResponseCode::BADNAME => "BADNAME",
+        ResponseCode::BADALG => "BADALG",
+        ResponseCode::BADTRUNC => "BADTRUNC",
+        ResponseCode::BADCOOKIE => "BADCOOKIE",
+        ResponseCode::Unknown(code) => return format!("RCODE{}", code),
+    };
+    name.to_string()
+}
+
+/// 返回要查询的名称。查询PTR记录时，IP地址会转换为对应的 arpa 名称。
+fn query_name(name: &str, kind: RecordKind) -> Result<Name> {
+    match (kind, name.parse::<IpAddr>()) {
+        (RecordKind::Ptr, Ok(ip)) => Ok(Name::from(ip)),
+        _ => Name::from_str_relaxed(name).with_context(|| format!("invalid domain name {:?}", name)),
+    }
+}
+
+/// 查询记录并返回响应码和应答。名称不存在或没有对应记录不视为错误。
+async fn lookup_answers(resolver: &TokioAsyncResolver, name: Name, record_type: RecordType) -> Result<(ResponseCode, Vec<Answer>)> {
+    match resolver.lookup(name, record_type).await {
+        Ok(lookup) => Ok((
+            ResponseCode::NoError,
+            lookup
+                .record_iter()
+                .map(|record| Answer {
+                    name: record.name().to_string(),
+                    record_type: record.record_type().to_string(),
+                    ttl: record.ttl(),
+                    data: record.data().map(ToString::to_string).unwrap_or_default(),
+                })
+                .collect(),
+        )),
+        Err(e) => match e.kind() {
+            ResolveErrorKind::NoRecordsFound { response_code, .. } => Ok((*response_code, Vec::new())),
+            _ => Err(e.into()),
+        },
+    }
+}
+
+/// 名称服务中某个来源的查询结果
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepOutcome {
+    /// 该来源给出了应答
+    Answered,
+
+    /// 该来源没有匹配的记录
+    NoMatch,
+
+    /// 查询该来源失败
+    Failed,
+
+    /// 该来源不受支持或不适用于查询的名称，未检查
+    NotInspected,
+}
+
+impl Display for StepOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StepOutcome::Answered => write!(f, "{}", "answered".green().bold()),
+            StepOutcome::NoMatch => write!(f, "{}", "no match".yellow()),
+            StepOutcome::Failed => write!(f, "{}", "failed".red()),
+            StepOutcome::NotInspected => write!(f, "{}", "not inspected".bright_black()),
+        }
+    }
+}
+
+/// 按名称服务切换配置查询的一步
+#[derive(Serialize, Debug)]
+pub struct ResolutionStep {
+    /// `/etc/nsswitch.conf` 中的来源，例如 files 或 dns
+    pub source: String,
+
+    /// 查询的DNS服务器，仅适用于 dns 来源
+    pub server: Option<SocketAddr>,
+
+    pub outcome: StepOutcome,
+
+    /// 该来源给出的应答，或失败的原因
+    pub detail: Option<String>,
+}
+
+impl Display for ResolutionStep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.source.bold())?;
+        if let Some(server) = self.server {
+            write!(f, " {}", server)?;
+        }
+        write!(f, "\t{}", self.outcome)?;
+        if let Some(detail) = &self.detail {
+            write!(f, "\t{}", detail)?;
+        }
+        Ok(())
+    }
+}
+
+/// 按照 `/etc/nsswitch.conf` 中 `hosts:` 的顺序逐个检查来源，说明哪个来源给出了应答。
+/// files 对应 `/etc/hosts`，dns 会逐个查询系统配置的DNS服务器；其他来源不受支持。
+/// 某个来源给出应答后，之后的来源不再检查。
+///
+/// # Errors
+///
+/// 如果无法读取名称服务配置或DNS配置。
+pub async fn explain_resolution(name: &str, kind: RecordKind) -> Result<Vec<ResolutionStep>> {
+    let hosts = hosts::hosts().await?;
+    let config = list_dns_servers().await?;
+    let mut servers: Vec<SocketAddr> = Vec::new();
+    for nameserver in &config.nameservers {
+        let server = SocketAddr::new(nameserver.address, nameserver.port);
+        if !servers.contains(&server) {
+            servers.push(server);
+        }
+    }
+
+    explain_with(name, kind, &hosts, &servers).await
+}
+
+/// 按 `hosts` 中的查询顺序检查来源，dns 来源逐个通过UDP查询 `servers`。
+/// DNS服务器的应答不会来自 hosts 文件，hosts 文件只在 files 来源中检查。
+async fn explain_with(name: &str, kind: RecordKind, hosts: &hosts::Hosts, servers: &[SocketAddr]) -> Result<Vec<ResolutionStep>> {
+    let record_type = RecordType::from(kind);
+    let mut steps = Vec::new();
+
+    for source in &hosts.nsswitch {
+        let mut step = |server, outcome, detail| steps.push(ResolutionStep {
+            source: source.clone(),
+            server,
+            outcome,
+            detail,
+        });
+
+        match source.as_str() {
+            "files" => {
+                let matches = hosts
+                    .entries
+                    .iter()
+                    .filter_map(|entry| match (kind, name.parse::<IpAddr>()) {
+                        (RecordKind::A, _) if entry.address.is_ipv4() && entry.matches(name) => Some(entry.address.to_string()),
+                        (RecordKind::Aaaa, _) if entry.address.is_ipv6() && entry.matches(name) => Some(entry.address.to_string()),
+                        (RecordKind::Ptr, Ok(ip)) if entry.address == ip => entry.names.first().cloned(),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+
+                if matches.is_empty() {
+                    step(None, StepOutcome::NoMatch, None);
+                } else {
+                    step(None, StepOutcome::Answered, Some(matches.join(" ")));
+                    return Ok(steps);
+                }
+            }
+            "dns" => {
+                let query = query_name(name, kind)?;
+                if is_loopback_name(&query) {
+                    step(None, StepOutcome::NotInspected, Some("special-use name, not sent to dns servers".to_string()));
+                    continue;
+                }
+
+                for &server in servers {
+                    let dns_server = DnsServer { address: server, transport: DnsTransport::Udp, tls_name: None };
+                    let lookup = match server_resolver(&dns_server) {
+                        Ok(resolver) => lookup_answers(&resolver, query.clone(), record_type).await,
+                        Err(e) => Err(e),
+                    };
+
+                    match lookup {
+                        Ok((_, answers)) if !answers.is_empty() => {
+                            let data = answers.into_iter().map(|answer| answer.data).collect::<Vec<_>>();
+                            step(Some(server), StepOutcome::Answered, Some(data.join(" ")));
+                            return Ok(steps);
+                        }
+                        Ok((status, _)) => step(Some(server), StepOutcome::NoMatch, Some(response_code_name(status))),
+                        Err(e) => step(Some(server), StepOutcome::Failed, Some(format!("{:#}", e))),
+                    }
+                }
+            }
+            _ => step(None, StepOutcome::NotInspected, None),
+        }
+    }
+
+    Ok(steps)
+}
+
+/// 健康检查时查询的名称
+pub const DNS_CHECK_NAME: &str = "example.com.";
+
+/// 健康检查中单次查询的超时时间
+pub const DNS_CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// 一个DNS服务器的健康检查结果
+#[derive(Serialize, Debug)]
+pub struct DnsServerCheck {
+    /// 被检查的DNS服务器
+    pub server: SocketAddr,
+
+    /// 服务器是否应答了查询
+    pub reachable: bool,
+
+    /// 查询耗时，单位为毫秒
+    pub latency_ms: Option<f64>,
+
+    /// 服务器是否提供递归查询
+    pub recursive: Option<bool>,
+
+    /// 服务器是否为不存在的域名返回了地址，即劫持了 NXDOMAIN 应答
+    pub nxdomain_hijacking: Option<bool>,
+
+    /// 服务器不可达时的错误原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Display for DnsServerCheck {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\t", self.server.to_string().bold())?;
+
+        if !self.reachable {
+            return write!(
+                f,
+                "{} ({})",
+                "unreachable".red(),
+                self.error.as_deref().unwrap_or("no response"),
+            );
+        }
+
+        let latency_ms = self.latency_ms.unwrap_or_default();
+        let latency = format!("{:.1} ms", latency_ms);
+        let latency = match latency_ms {
+            _ if latency_ms > 500.0 => latency.red(),
+            _ if latency_ms > 100.0 => latency.yellow(),
+            _ => latency.green(),
+        };
+
+        let recursive = match self.recursive {
+            Some(true) => "recursive".green(),
+            _ => "not recursive".yellow(),
+        };
+
+        let hijacking = match self.nxdomain_hijacking {
+            Some(true) => "hijacks NXDOMAIN".red(),
+            Some(false) => "honest NXDOMAIN".green(),
+            None => "NXDOMAIN unknown".yellow(),
+        };
+
+        write!(f, "{}\t{}\t{}\t{}", "reachable".green(), latency, recursive, hijacking)
+    }
+}
+
+/// 并发检查系统配置的每个DNS服务器。
+/// 每个服务器地址只检查一次，检查结果按系统配置中的顺序返回。
+///
+/// # Errors
+///
+/// 如果无法读取系统配置。单个服务器不可达不会导致错误，而是体现在检查结果中。
+pub async fn check_dns_servers() -> Result<Vec<DnsServerCheck>> {
+    let config = list_dns_servers().await?;
+    let mut servers: Vec<SocketAddr> = Vec::new();
+    for nameserver in &config.nameservers {
+        let server = SocketAddr::new(nameserver.address, nameserver.port);
+        if !servers.contains(&server) {
+            servers.push(server);
+        }
+    }
+
+    let checks = servers
+        .into_iter()
+        .map(|server| tokio::spawn(check_dns_server(server, DNS_CHECK_TIMEOUT)))
+        .collect::<Vec<_>>();
+
+    let mut results = Vec::with_capacity(checks.len());
+    for check in checks {
+        results.push(check.await?);
+    }
+    Ok(results)
+}
+
+/// 检查单个DNS服务器：
+/// 查询 [`DNS_CHECK_NAME`] 以测量可达性、响应时间和递归能力，
+/// 再查询一个随机的不存在域名以检测 NXDOMAIN 劫持。
+pub async fn check_dns_server(server: SocketAddr, timeout: Duration) -> DnsServerCheck {
+    let check_name = Name::from_ascii(DNS_CHECK_NAME).expect("DNS_CHECK_NAME is a valid name");
+
+    let (response, latency) = match query_dns_server(server, &check_name, RecordType::A, timeout).await {
+        Ok(answer) => answer,
+        Err(e) => return DnsServerCheck {
+            server,
+            reachable: false,
+            latency_ms: None,
+            recursive: None,
+            nxdomain_hijacking: None,
+            error: Some(e.to_string()),
+        },
+    };
+
+    let nxdomain_hijacking = match Name::from_ascii(format!("whatis-{:x}.com.", nonce())) {
+        Ok(name) => query_dns_server(server, &name, RecordType::A, timeout)
+            .await
+            .ok()
+            .map(|(response, _)| response.response_code() == ResponseCode::NoError && !response.answers().is_empty()),
+        Err(_) => None,
+    };
+
+    DnsServerCheck {
+        server,
+        reachable: true,
+        latency_ms: Some(latency.as_secs_f64() * 1000.0),
+        recursive: Some(response.recursion_available()),
+        nxdomain_hijacking,
+        error: None,
+    }
+}
+
+/// 通过UDP向DNS服务器发送一次递归查询，返回应答及耗时。
+/// 不经过解析器，因此可以读取应答头部的标志位。
+async fn query_dns_server(server: SocketAddr, name: &Name, record_type: RecordType, timeout: Duration) -> Result<(Message, Duration)> {
+    let bind_addr: SocketAddr = if server.is_ipv4() {
+        (Ipv4Addr::UNSPECIFIED, 0).into()
+    } else {
+        (Ipv6Addr::UNSPECIFIED, 0).into()
+    };
+    let socket = UdpSocket::bind(bind_addr).await?;
+    socket.connect(server).await?;
+
+    let id = nonce() as u16;
+    let mut query = Message::new();
+    query
+        .set_id(id)
+        .set_recursion_desired(true)
+        .add_query(Query::query(name.clone(), record_type));
+
+    let start = Instant::now();
+    socket.send(&query.to_bytes()?).await?;
+
+    let mut buffer = [0u8; 4096];
+    let response = tokio::time::timeout(timeout, async {
+        loop {
+            let len = socket.recv(&mut buffer).await?;
+            // 忽略无法解析的数据包和与本次查询无关的迟到应答，继续等待直到超时
+            match Message::from_vec(&buffer[..len]) {
+                Ok(response) if response.id() == id && response.message_type() == MessageType::Response => {
+                    return Ok::<_, anyhow::Error>(response);
+                }
+                _ => continue,
+            }
+        }
+    })
+        .await
+        .with_context(|| format!("no response within {} seconds", timeout.as_secs_f64()))??;
+
+    Ok((response, start.elapsed()))
+}
+
+/// 生成一个不需要密码学强度的随机数，用于查询ID和随机域名
+fn nonce() -> u64 {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos() as u64;
+    nanos ^ (u64::from(std::process::id()) << 32)
+}
+
+/// 内核套接字表所在的目录
+pub const PROC_NET: &str = "/proc/net";
+
+/// TCP 套接字的 LISTEN 状态
+const TCP_LISTEN: u8 = 0x0A;
+
+/// 未连接的 UDP 套接字处于 CLOSE 状态。
+/// 内核无法区分等待数据的服务端套接字和只用 sendto 发送数据的客户端套接字，
+/// 因此后者也会出现在结果中。
+const UDP_UNCONNECTED: u8 = 0x07;
+
+/// 套接字的传输层协议
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SocketProtocol {
+    Tcp,
+    Udp,
+}
+
+impl Display for SocketProtocol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SocketProtocol::Tcp => write!(f, "tcp"),
+            SocketProtocol::Udp => write!(f, "udp"),
+        }
+    }
+}
+
+/// 正在监听的套接字
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct ListeningSocket {
+    /// 传输层协议
+    pub protocol: SocketProtocol,
+
+    /// 本地地址
+    pub address: IpAddr,
+
+    /// 本地端口
+    pub port: u16,
+
+    /// 拥有该套接字的进程ID，没有权限读取时为空
+    pub pid: Option<u32>,
+
+    /// 拥有该套接字的进程名称
+    pub process: Option<String>,
+
+    /// 套接字的 inode 编号，用于查找所属进程
+    #[serde(skip)]
+    inode: u64,
+}
+
+impl Display for ListeningSocket {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let owner = match (self.pid, &self.process) {
+            (Some(pid), Some(process)) => format!("{}/{}", pid, process),
+            (Some(pid), None) => pid.to_string(),
+            _ => "-".to_string(),
+        };
+
+        write!(
+            f,
+            "{}\t{}\t{}",
+            self.protocol,
+            SocketAddr::new(self.address, self.port).to_string().bold(),
+            owner.cyan(),
+        )
+    }
+}
+
+/// 筛选监听套接字的条件
+#[derive(Clone, Debug, Default)]
+pub struct PortFilter {
+    /// 只保留这些协议，为空时保留全部协议
+    pub protocols: Vec<SocketProtocol>,
+
+    /// 只保留这个端口
+    pub port: Option<u16>,
+}
+
+impl PortFilter {
+    fn matches(&self, socket: &ListeningSocket) -> bool {
+        (self.protocols.is_empty() || self.protocols.contains(&socket.protocol))
+            && self.port.is_none_or(|port| port == socket.port)
+    }
+}
+
+/// 解析 `/proc/net/{tcp,tcp6,udp,udp6}` 的内容，只返回处于监听状态的套接字。
+/// 地址以主机字节序的十六进制存储，端口以十六进制存储。
+///
+/// # Errors
+///
+/// 如果某一行的字段缺失或不是合法的十六进制数。
+pub fn parse_socket_table(contents: &str, protocol: SocketProtocol) -> Result<Vec<ListeningSocket>> {
+    let listening_state = match protocol {
+        SocketProtocol::Tcp => TCP_LISTEN,
+        SocketProtocol::Udp => UDP_UNCONNECTED,
+    };
+
+    let mut sockets = Vec::new();
+    for line in contents.lines().skip(1).filter(|line| !line.trim().is_empty()) {
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        if fields.len() < 10 {
+            bail!("malformed socket entry: {}", line.trim());
+        }
+
+        let state = u8::from_str_radix(fields[3], 16)
+            .with_context(|| format!("invalid socket state {:?}", fields[3]))?;
+        if state != listening_state {
+            continue;
+        }
+        // 有远端端口的套接字已经与对端关联，不是在监听
+        if fields[2].rsplit_once(':').is_some_and(|(_, port)| !port.trim_start_matches('0').is_empty()) {
+            continue;
+        }
+
+        let (address, port) = fields[1]
+            .split_once(':')
+            .with_context(|| format!("invalid socket address {:?}", fields[1]))?;
+
+        sockets.push(ListeningSocket {
+            protocol,
+            address: parse_proc_address(address)?,
+            port: u16::from_str_radix(port, 16).with_context(|| format!("invalid socket port {:?}", port))?,
+            pid: None,
+            process: None,
+            inode: fields[9].parse().with_context(|| format!("invalid socket inode {:?}", fields[9]))?,
+        });
+    }
+
+    Ok(sockets)
+}
+
+/// 解析 `/proc/net` 中以主机字节序存储的十六进制地址。
+/// IPv6 地址由四个主机字节序的32位字组成。
+fn parse_proc_address(hex: &str) -> Result<IpAddr> {
+    let word = |chunk: &str| u32::from_str_radix(chunk, 16)
+        .map(|value| u32::from_be(value).to_be_bytes())
+        .with_context(|| format!("invalid socket address {:?}", hex));
+
+    match hex.len() {
+        8 => Ok(IpAddr::V4(Ipv4Addr::from(word(hex)?))),
+        32 => {
+            let mut octets = [0u8; 16];
+            for (i, chunk) in octets.chunks_mut(4).enumerate() {
+                chunk.copy_from_slice(&word(&hex[i * 8..(i + 1) * 8])?);
+            }
+            Ok(IpAddr::V6(Ipv6Addr::from(octets)))
+        }
+        _ => bail!("invalid socket address {:?}", hex),
+    }
+}
+
+/// 扫描 `/proc/<pid>/fd`，建立套接字 inode 到进程ID的映射。
+/// 没有权限读取的进程会被跳过。
+pub fn socket_owners(proc_root: &Path) -> HashMap<u64, u32> {
+    let mut owners = HashMap::new();
+
+    let Ok(entries) = std::fs::read_dir(proc_root) else {
+        return owners;
+    };
+
+    for entry in entries.flatten() {
+        let Some(pid) = entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) else {
+            continue;
+        };
+        let Ok(fds) = std::fs::read_dir(entry.path().join("fd")) else {
+            continue;
+        };
+
+        for fd in fds.flatten() {
+            let inode = std::fs::read_link(fd.path())
+                .ok()
+                .and_then(|target| {
+                    target
+                        .to_str()
+                        .and_then(|target| target.strip_prefix("socket:["))
+                        .and_then(|target| target.strip_suffix(']'))
+                        .and_then(|inode| inode.parse::<u64>().ok())
+                });
+            if let Some(inode) = inode {
+                owners.entry(inode).or_insert(pid);
+            }
+        }
+    }
+
+    owners
+}
+
+/// 列出系统中正在监听的TCP和UDP套接字，并尽可能找出所属进程。
+/// 结果按协议和端口排序。
+///
+/// # Errors
+///
+/// 如果无法读取或解析内核的套接字表。
+pub async fn listening_sockets(filter: PortFilter) -> Result<Vec<ListeningSocket>> {
+    spawn_blocking(move || {
+        let tables = [
+            ("tcp", SocketProtocol::Tcp),
+            ("tcp6", SocketProtocol::Tcp),
+            ("udp", SocketProtocol::Udp),
+            ("udp6", SocketProtocol::Udp),
+        ];
+
+        let mut sockets = Vec::new();
+        for (table, protocol) in tables {
+            let path = Path::new(PROC_NET).join(table);
+            let contents = match std::fs::read_to_string(&path) {
+                Ok(contents) => contents,
+                // 系统禁用IPv6时没有 tcp6 和 udp6
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e).with_context(|| format!("reading {} failed", path.display())),
+            };
+            sockets.extend(
+                parse_socket_table(&contents, protocol)?
+                    .into_iter()
+                    .filter(|socket| filter.matches(socket)),
+            );
+        }
+
+        let owners = socket_owners(Path::new("/proc"));
+        let system = System::new_with_specifics(RefreshKind::new().with_processes(ProcessRefreshKind::new()));
+        for socket in sockets.iter_mut() {
+            socket.pid = owners.get(&socket.inode).copied();
+            socket.process = socket.pid
+                .and_then(|pid| system.process(Pid::from_u32(pid)))
+                .map(|process| process.name().to_string());
+        }
+
+        dedup_sockets(&mut sockets);
+        Ok(sockets)
+    }).await?
+}
+
+/// 按协议、端口和地址排序，并合并相同协议、地址和端口的套接字。
+/// 使用 SO_REUSEPORT 的进程会在同一地址和端口上打开多个套接字，它们的 inode 不同；
+/// 合并时优先保留已知所属进程的一个。
+fn dedup_sockets(sockets: &mut Vec<ListeningSocket>) {
+    sockets.sort_by_key(|socket| (socket.protocol, socket.port, socket.address, socket.pid.is_none()));
+    sockets.dedup_by(|a, b| (a.protocol, a.address, a.port) == (b.protocol, b.address, b.port));
+}
+
+/// 内核ARP表文件
+pub const PROC_NET_ARP: &str = "/proc/net/arp";
+
+/// ARP表项已解析出硬件地址
+const ATF_COM: u32 = 0x02;
+
+/// ARP表项为静态配置
+const ATF_PERM: u32 = 0x04;
+
+/// 本地网段上的一个邻居
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct Neighbor {
+    /// 邻居的IP地址
+    pub ip: IpAddr,
+
+    /// 邻居的硬件地址，尚未解析时为空
+    pub mac: Option<String>,
+
+    /// 邻居所在的网络接口
+    pub interface: String,
+
+    /// 邻居表项的状态，例如 reachable、stale 或 incomplete
+    pub state: String,
+}
+
+impl Display for Neighbor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let state = match self.state.as_str() {
+            "reachable" | "permanent" | "complete" => self.state.green(),
+            "failed" | "incomplete" => self.state.red(),
+            _ => self.state.yellow(),
+        };
+
+        write!(
+            f,
+            "{}\t{}\t{}\t{}",
+            self.ip.to_string().bold(),
+            self.mac.as_deref().unwrap_or("-"),
+            self.interface,
+            state,
+        )
+    }
+}
+
+/// 解析 `/proc/net/arp` 的内容。
+/// ARP表不提供邻居的可达性状态，因此状态只区分 complete、permanent 和 incomplete。
+///
+/// # Errors
+///
+/// 如果某一行的字段缺失或不合法。
+pub fn parse_arp_table(contents: &str) -> Result<Vec<Neighbor>> {
+    contents
+        .lines()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            if fields.len() < 6 {
+                bail!("malformed arp entry: {}", line.trim());
+            }
+
+            let flags = u32::from_str_radix(fields[2].trim_start_matches("0x"), 16)
+                .with_context(|| format!("invalid arp flags {:?}", fields[2]))?;
+            let state = match flags {
+                _ if flags & ATF_PERM != 0 => "permanent",
+                _ if flags & ATF_COM != 0 => "complete",
+                _ => "incomplete",
+            };
+
+            Ok(Neighbor {
+                ip: fields[0].parse().with_context(|| format!("invalid arp address {:?}", fields[0]))?,
+                mac: Some(fields[3].to_string()).filter(|mac| mac != "00:00:00:00:00:00"),
+                interface: fields[5].to_string(),
+                state: state.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// 列出IPv4和IPv6的邻居表。
+/// 邻居通过 netlink 查询；netlink 不可用时退回到只包含IPv4邻居的 `/proc/net/arp`。
+///
+/// # Errors
+///
+/// 如果 netlink 查询失败，并且无法读取或解析ARP表。
+pub async fn neighbors() -> Result<Vec<Neighbor>> {
+    spawn_blocking(|| match netlink_neighbors() {
+        Ok(neighbors) => Ok(neighbors),
+        Err(e) => arp_neighbors().with_context(|| format!("querying the neighbors over netlink failed ({:#})", e)),
+    }).await?
+}
+
+/// 读取 `/proc/net/arp`。没有ARP表的系统（例如未启用IPv4的内核）视为没有邻居。
+fn arp_neighbors() -> Result<Vec<Neighbor>> {
+    match std::fs::read_to_string(PROC_NET_ARP) {
+        Ok(contents) => parse_arp_table(&contents),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e).with_context(|| format!("reading {} failed", PROC_NET_ARP)),
+    }
+}
+
+/// 通过 netlink 导出内核的IPv4和IPv6邻居表
+#[cfg(target_os = "linux")]
+fn netlink_neighbors() -> Result<Vec<Neighbor>> {
+    use netlink_packet_core::{NetlinkHeader, NetlinkMessage, NetlinkPayload, NLM_F_DUMP, NLM_F_REQUEST};
+    use netlink_packet_route::{constants::AF_UNSPEC, NeighbourMessage, RtnlMessage};
+    use netlink_sys::{protocols::NETLINK_ROUTE, Socket, SocketAddr};
+
+    let mut header = NetlinkHeader::default();
+    header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+    let mut message = NeighbourMessage::default();
+    message.header.family = AF_UNSPEC as u8;
+
+    let mut request = NetlinkMessage::new(header, NetlinkPayload::from(RtnlMessage::GetNeighbour(message)));
+    request.finalize();
+    let mut buffer = vec![0; request.header.length as usize];
+    request.serialize(&mut buffer);
+
+    let mut socket = Socket::new(NETLINK_ROUTE)?;
+    socket.bind_auto()?;
+    socket.connect(&SocketAddr::new(0, 0))?;
+    socket.send(&buffer, 0)?;
+
+    let names = interface_names();
+    let mut neighbors = Vec::new();
+    let mut receive_buffer = vec![0; 32 * 1024];
+
+    loop {
+        let size = socket.recv(&mut &mut receive_buffer[..], 0)?;
+        let mut offset = 0;
+
+        while offset < size {
+            let response = NetlinkMessage::<RtnlMessage>::deserialize(&receive_buffer[offset..size])
+                .map_err(|e| anyhow::anyhow!("invalid netlink message: {}", e))?;
+            if response.header.length == 0 {
+                break;
+            }
+            offset += response.header.length as usize;
+
+            match response.payload {
+                NetlinkPayload::Done(_) => return Ok(neighbors),
+                NetlinkPayload::Error(e) => bail!("netlink error: {}", e),
+                NetlinkPayload::InnerMessage(RtnlMessage::NewNeighbour(entry)) => {
+                    neighbors.extend(neighbor_from_message(&entry, &names));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// 将一条 netlink 邻居消息转换为邻居。
+/// NOARP 表项（回环地址、组播地址等）不是本地网段上的设备，与 `ip neigh` 一样跳过；
+/// 全零的硬件地址表示尚未解析，与ARP表的解析一致。
+#[cfg(target_os = "linux")]
+fn neighbor_from_message(
+    entry: &netlink_packet_route::NeighbourMessage,
+    names: &HashMap<u32, String>,
+) -> Option<Neighbor> {
+    use netlink_packet_route::{constants::*, neighbour::Nla};
+
+    if entry.header.state & NUD_NOARP != 0 {
+        return None;
+    }
+
+    let mut ip = None;
+    let mut mac = None;
+    for nla in &entry.nlas {
+        match nla {
+            Nla::Destination(bytes) => {
+                ip = match bytes.len() {
+                    4 => <[u8; 4]>::try_from(bytes.as_slice()).ok().map(|octets| IpAddr::V4(Ipv4Addr::from(octets))),
+                    _ => <[u8; 16]>::try_from(bytes.as_slice()).ok().map(|octets| IpAddr::V6(Ipv6Addr::from(octets))),
+                };
+            }
+            Nla::LinkLocalAddress(bytes) if bytes.iter().any(|byte| *byte != 0) => {
+                mac = Some(bytes.iter().map(|byte| format!("{:02x}", byte)).collect::<Vec<_>>().join(":"));
+            }
+            _ => {}
+        }
+    }
+
+    let state = match entry.header.state {
+        NUD_INCOMPLETE => "incomplete",
+        NUD_REACHABLE => "reachable",
+        NUD_STALE => "stale",
+        NUD_DELAY => "delay",
+        NUD_PROBE => "probe",
+        NUD_FAILED => "failed",
+        NUD_PERMANENT => "permanent",
+        _ => "none",
+    };
+
+    Some(Neighbor {
+        ip: ip?,
+        mac,
+        interface: names.get(&entry.header.ifindex).cloned().unwrap_or_else(|| entry.header.ifindex.to_string()),
+        state: state.to_string(),
+    })
+}
+
+/// 其他平台没有 netlink
+#[cfg(not(target_os = "linux"))]
+fn netlink_neighbors() -> Result<Vec<Neighbor>> {
+    bail!("netlink is only available on linux")
+}
+
+/// 网络接口状态所在的目录
+pub const SYS_CLASS_NET: &str = "/sys/class/net";
+
+/// 读取 `/sys/class/net` 建立接口索引到接口名称的映射
+fn interface_names() -> HashMap<u32, String> {
+    let Ok(entries) = std::fs::read_dir(SYS_CLASS_NET) else {
+        return HashMap::new();
+    };
+
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let index = std::fs::read_to_string(entry.path().join("ifindex")).ok()?;
+            Some((index.trim().parse().ok()?, entry.file_name().to_string_lossy().into_owned()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trust_dns_resolver::proto::rr::{RData, Record};
+
+    #[test]
+    fn dns_config_merges_protocols_and_keeps_search_domains_and_options() {
+        let server = SocketAddr::new(IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x53)), 5353);
+        let mut conf = ResolverConfig::from_parts(
+            Some(Name::from_ascii("corp.example.").unwrap()),
+            vec![Name::from_ascii("a.example.").unwrap(), Name::from_ascii("b.example.").unwrap()],
+            vec![NameServerConfig::new(server, Protocol::Udp), NameServerConfig::new(server, Protocol::Tcp)],
+        );
+        conf.add_name_server(NameServerConfig::new(SocketAddr::from((Ipv4Addr::new(192, 0, 2, 53), 53)), Protocol::Udp));
+
+        let mut opts = ResolverOpts::default();
+        opts.ndots = 2;
+        opts.timeout = Duration::from_millis(1500);
+        opts.attempts = 4;
+
+        let config = DnsConfig::new(&conf, &opts);
+        assert_eq!(config.nameservers.len(), 2);
+        assert_eq!(config.nameservers[0].address, server.ip());
+        assert_eq!(config.nameservers[0].port, 5353);
+        assert_eq!(config.nameservers[0].protocols, ["udp", "tcp"]);
+        assert_eq!(config.nameservers[1].address, IpAddr::V4(Ipv4Addr::new(192, 0, 2, 53)));
+        assert_eq!(config.nameservers[1].port, 53);
+        assert_eq!(config.nameservers[1].protocols, ["udp"]);
+        assert_eq!(config.search_domains, ["a.example.", "b.example."]);
+        assert_eq!(config.options, DnsOptions { ndots: 2, timeout_seconds: 1.5, attempts: 4 });
+    }
+
+    #[test]
+    fn dns_config_uses_the_domain_without_a_search_list() {
+        let conf = ResolverConfig::from_parts(Some(Name::from_ascii("corp.example.").unwrap()), vec![], vec![]);
+        let config = DnsConfig::new(&conf, &ResolverOpts::default());
+        assert!(config.nameservers.is_empty());
+        assert_eq!(config.search_domains, ["corp.example."]);
+    }
+
+    const NET_DEV: &str = "\
+Inter-|   Receive                                                |  Transmit
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    lo: 49820817    5538    0    0    0     0          0         0 49820817    5538    0    0    0     0       0          0
+  eth0: 1000 10 1 2 0 0 0 0 2000 20 3 4 0 0 0 0
+";
+
+    #[test]
+    fn parse_net_dev_reads_rx_and_tx_counters() {
+        let stats = parse_net_dev(NET_DEV).unwrap();
+
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats["lo"].rx_bytes, 49820817);
+        assert_eq!(stats["lo"].tx_packets, 5538);
+
+        let eth0 = &stats["eth0"];
+        assert_eq!((eth0.rx_bytes, eth0.rx_packets, eth0.rx_errors, eth0.rx_dropped), (1000, 10, 1, 2));
+        assert_eq!((eth0.tx_bytes, eth0.tx_packets, eth0.tx_errors, eth0.tx_dropped), (2000, 20, 3, 4));
+    }
+
+    #[test]
+    fn parse_net_dev_rejects_short_or_invalid_lines() {
+        let header = NET_DEV.lines().take(2).collect::<Vec<_>>().join("\n");
+        assert!(parse_net_dev(&format!("{}\n  eth0: 1 2 3\n", header)).is_err());
+        assert!(parse_net_dev(&format!("{}\n  eth0: 1 2 x 4 5 6 7 8 9 10 11 12\n", header)).is_err());
+    }
+
+    fn stats(rx_bytes: u64, tx_bytes: u64) -> InterfaceStats {
+        InterfaceStats {
+            rx_bytes,
+            rx_packets: 0,
+            rx_errors: 0,
+            rx_dropped: 0,
+            tx_bytes,
+            tx_packets: 0,
+            tx_errors: 0,
+            tx_dropped: 0,
+        }
+    }
+
+    #[test]
+    fn interface_rate_divides_the_delta_by_the_interval() {
+        let rate = InterfaceRate::between(&stats(1000, 500), &stats(3000, 1500), Duration::from_millis(500));
+        assert_eq!(rate.rx_bytes_per_second, 4000);
+        assert_eq!(rate.tx_bytes_per_second, 2000);
+    }
+
+    #[test]
+    fn interface_rate_treats_a_counter_reset_as_zero() {
+        let rate = InterfaceRate::between(&stats(5000, 5000), &stats(100, 6000), Duration::from_secs(1));
+        assert_eq!(rate.rx_bytes_per_second, 0);
+        assert_eq!(rate.tx_bytes_per_second, 1000);
+    }
+
+    #[test]
+    fn parse_route_table_decodes_little_endian_addresses() {
+        let routes = parse_route_table("\
+Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT
+eth0\t00000000\t0101A8C0\t0003\t0\t0\t100\t00000000\t0\t0\t0
+eth0\t0001A8C0\t00000000\t0001\t0\t0\t100\t00FFFFFF\t0\t0\t0
+").unwrap();
+
+        assert_eq!(routes.len(), 2);
+        assert!(routes[0].default);
+        assert_eq!(routes[0].gateway, Some("192.168.1.1".parse().unwrap()));
+        assert_eq!(routes[0].flags, "UG");
+        assert_eq!(routes[0].metric, 100);
+
+        assert!(!routes[1].default);
+        assert_eq!(routes[1].destination, "192.168.1.0".parse::<IpAddr>().unwrap());
+        assert_eq!(routes[1].prefix_length, 24);
+        assert_eq!(routes[1].gateway, None);
+
+        let routes = Routes::new(routes);
+        assert_eq!(routes.gateway, Some("192.168.1.1".parse().unwrap()));
+    }
+
+    #[test]
+    fn parse_route_table_rejects_malformed_lines() {
+        assert!(parse_route_table("header\neth0\t00000000\n").is_err());
+        assert!(parse_route_table("header\neth0\tzzzzzzzz\t00000000\t0001\t0\t0\t0\t00000000\n").is_err());
+    }
+
+    #[test]
+    fn parse_ipv6_route_table_skips_reject_routes() {
+        let routes = parse_ipv6_route_table("\
+fe800000000000000000000000000000 40 00000000000000000000000000000000 00 00000000000000000000000000000000 00000100 00000001 00000000 00000001     eth0
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 fe800000000000000000000000000001 00000400 00000001 00000000 00000003     eth0
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 00000000000000000000000000000000 ffffffff 00000001 00000000 00200200       lo
+").unwrap();
+
+        assert_eq!(routes.len(), 2);
+        assert_eq!(routes[0].destination, "fe80::".parse::<IpAddr>().unwrap());
+        assert_eq!(routes[0].prefix_length, 64);
+        assert_eq!(routes[0].gateway, None);
+
+        assert!(routes[1].default);
+        assert_eq!(routes[1].gateway, Some("fe80::1".parse().unwrap()));
+        assert_eq!(routes[1].metric, 1024);
+        assert_eq!(routes[1].interface, "eth0");
+    }
+
+    #[test]
+    fn response_code_name_uses_the_protocol_mnemonics() {
+        assert_eq!(response_code_name(ResponseCode::NoError), "NOERROR");
+        assert_eq!(response_code_name(ResponseCode::NXDomain), "NXDOMAIN");
+        assert_eq!(response_code_name(ResponseCode::ServFail), "SERVFAIL");
+        assert_eq!(response_code_name(ResponseCode::Unknown(3841)), "RCODE3841");
+    }
+
+    #[test]
+    fn is_loopback_name_matches_localhost_and_its_reverse_zones() {
+        for name in ["localhost", "foo.localhost.", "1.0.0.127.in-addr.arpa."] {
+            assert!(is_loopback_name(&Name::from_str_relaxed(name).unwrap()), "{}", name);
+        }
+        assert!(is_loopback_name(&Name::from(IpAddr::from(Ipv6Addr::LOCALHOST))));
+        for name in ["example.com", "localhost.example.com", "1.0.0.10.in-addr.arpa."] {
+            assert!(!is_loopback_name(&Name::from_str_relaxed(name).unwrap()), "{}", name);
+        }
+    }
+
+    #[tokio::test]
+    async fn query_dns_server_skips_malformed_and_mismatched_packets() {
+        let server = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let address = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buffer = [0u8; 512];
+            let (len, client) = server.recv_from(&mut buffer).await.unwrap();
+            let query = Message::from_vec(&buffer[..len]).unwrap();
+
+            server.send_to(b"not a dns message", client).await.unwrap();
+            let mut stale = query.clone();
+            stale.set_id(query.id().wrapping_add(1)).set_message_type(MessageType::Response);
+            server.send_to(&stale.to_bytes().unwrap(), client).await.unwrap();
+            let mut response = query.clone();
+            response.set_message_type(MessageType::Response).set_recursion_available(true);
+            server.send_to(&response.to_bytes().unwrap(), client).await.unwrap();
+        });
+
+        let name = Name::from_ascii("example.com.").unwrap();
+        let (response, _) = query_dns_server(address, &name, RecordType::A, Duration::from_secs(5)).await.unwrap();
+        assert!(response.recursion_available());
+    }
+
+    #[tokio::test]
+    async fn query_dns_server_times_out_without_a_valid_response() {
+        let server = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let address = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buffer = [0u8; 512];
+            let (_, client) = server.recv_from(&mut buffer).await.unwrap();
+            server.send_to(b"garbage", client).await.unwrap();
+            // 保持套接字打开，避免客户端收到端口不可达
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        });
+
+        let name = Name::from_ascii("example.com.").unwrap();
+        let error = query_dns_server(address, &name, RecordType::A, Duration::from_millis(200)).await.unwrap_err();
+        assert!(error.to_string().contains("no response"), "{}", error);
+    }
+
+    /// 从DNS查询构造应答，名称对应一条A记录 192.0.2.1
+    fn stand_in_response(query: &[u8]) -> Vec<u8> {
+        let query = Message::from_vec(query).unwrap();
+        let mut response = Message::new();
+        response
+            .set_id(query.id())
+            .set_message_type(MessageType::Response)
+            .set_recursion_available(true)
+            .add_queries(query.queries().to_vec());
+        for question in query.queries() {
+            if question.query_type() == RecordType::A {
+                response.add_answer(Record::from_rdata(question.name().clone(), 60, RData::A(Ipv4Addr::new(192, 0, 2, 1))));
+            }
+        }
+        response.to_bytes().unwrap()
+    }
+
+    /// 从DNS查询构造 NXDOMAIN 应答
+    fn nxdomain_response(query: &[u8]) -> Vec<u8> {
+        let query = Message::from_vec(query).unwrap();
+        let mut response = Message::new();
+        response
+            .set_id(query.id())
+            .set_message_type(MessageType::Response)
+            .set_recursion_available(true)
+            .set_response_code(ResponseCode::NXDomain)
+            .add_queries(query.queries().to_vec());
+        response.to_bytes().unwrap()
+    }
+
+    /// 在 127.0.0.1 的随机端口上启动一个UDP DNS服务器，用 `respond` 构造每条应答
+    async fn udp_stand_in(respond: fn(&[u8]) -> Vec<u8>) -> SocketAddr {
+        let socket = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let address = socket.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buffer = [0u8; 512];
+            loop {
+                let (len, client) = socket.recv_from(&mut buffer).await.unwrap();
+                socket.send_to(&respond(&buffer[..len]), client).await.unwrap();
+            }
+        });
+        address
+    }
+
+    /// 只为 192.0.2.1 应答PTR记录，其他查询应答 NXDOMAIN
+    fn ptr_response(query: &[u8]) -> Vec<u8> {
+        let message = Message::from_vec(query).unwrap();
+        let question = &message.queries()[0];
+        if question.query_type() != RecordType::PTR || question.name().to_ascii() != "1.2.0.192.in-addr.arpa." {
+            return nxdomain_response(query);
+        }
+
+        let mut response = Message::new();
+        response
+            .set_id(message.id())
+            .set_message_type(MessageType::Response)
+            .set_recursion_available(true)
+            .add_queries(message.queries().to_vec())
+            .add_answer(Record::from_rdata(
+                question.name().clone(),
+                60,
+                RData::PTR(Name::from_ascii("gateway.stand-in.test.").unwrap()),
+            ));
+        response.to_bytes().unwrap()
+    }
+
+    async fn stand_in_resolver(respond: fn(&[u8]) -> Vec<u8>) -> TokioAsyncResolver {
+        let port = udp_stand_in(respond).await.port();
+        server_resolver(&DnsServer::new(Ipv4Addr::LOCALHOST.into(), DnsTransport::Udp).with_port(Some(port))).unwrap()
+    }
+
+    #[tokio::test]
+    async fn attach_ip_ptrs_sets_the_name_of_each_address() {
+        let resolver = stand_in_resolver(ptr_response).await;
+        let mut ips = ["192.0.2.1", "192.0.2.2", "2001:db8::1"]
+            .iter()
+            .map(|address| Ip { address: address.parse().unwrap(), category: IpCategory::Public, ptr: None, country: None })
+            .collect::<Vec<_>>();
+
+        attach_ip_ptrs_with(&resolver, &mut ips).await.unwrap();
+
+        assert_eq!(ips[0].ptr.as_deref(), Some("gateway.stand-in.test"));
+        assert_eq!(ips[1].ptr, None);
+        assert_eq!(ips[2].ptr, None);
+    }
+
+    #[tokio::test]
+    async fn attach_interface_ptrs_leaves_failed_lookups_empty() {
+        let resolver = stand_in_resolver(nxdomain_response).await;
+        let mut interfaces = vec![Interface {
+            name: "eth0".to_string(),
+            ip: "192.0.2.1".to_string(),
+            stats: None,
+            rate: None,
+            ptr: Some("stale.example".to_string()),
+            wireless: None,
+        }];
+
+        attach_interface_ptrs_with(&resolver, &mut interfaces).await.unwrap();
+        assert_eq!(interfaces[0].ptr, None);
+
+        let resolver = stand_in_resolver(ptr_response).await;
+        attach_interface_ptrs_with(&resolver, &mut interfaces).await.unwrap();
+        assert_eq!(interfaces[0].ptr.as_deref(), Some("gateway.stand-in.test"));
+    }
+
+    #[tokio::test]
+    async fn reverse_lookup_keeps_the_order_of_the_addresses() {
+        let resolver = stand_in_resolver(ptr_response).await;
+        let addresses = ["192.0.2.2".parse().unwrap(), "192.0.2.1".parse().unwrap()];
+
+        let names = reverse_lookup_all(&resolver, &addresses).await.unwrap();
+        assert_eq!(names, [None, Some("gateway.stand-in.test".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn resolve_queries_an_explicit_server_over_udp() {
+        let port = udp_stand_in(stand_in_response).await.port();
+
+        let server = DnsServer::new(Ipv4Addr::LOCALHOST.into(), DnsTransport::Udp).with_port(Some(port));
+        let resolution = resolve("stand-in.test", RecordKind::A, Some(&server), DnsTransport::Udp).await.unwrap();
+
+        assert_eq!(resolution.status, "NOERROR");
+        assert_eq!(resolution.server, Some(SocketAddr::from((Ipv4Addr::LOCALHOST, port))));
+        assert_eq!(resolution.transport, DnsTransport::Udp);
+        assert!(!resolution.answered_locally);
+        assert_eq!(resolution.answers.len(), 1);
+        assert_eq!(resolution.answers[0].data, "192.0.2.1");
+    }
+
+    #[tokio::test]
+    async fn resolve_queries_an_explicit_server_over_tcp() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                tokio::spawn(async move {
+                    // TCP上的DNS消息带有两个字节的长度前缀
+                    while let Ok(len) = stream.read_u16().await {
+                        let mut query = vec![0u8; usize::from(len)];
+                        stream.read_exact(&mut query).await.unwrap();
+                        let response = stand_in_response(&query);
+                        stream.write_u16(response.len() as u16).await.unwrap();
+                        stream.write_all(&response).await.unwrap();
+                    }
+                });
+            }
+        });
+
+        let server = DnsServer::new(Ipv4Addr::LOCALHOST.into(), DnsTransport::Tcp).with_port(Some(port));
+        let resolution = resolve("stand-in.test", RecordKind::A, Some(&server), DnsTransport::Tcp).await.unwrap();
+
+        assert_eq!(resolution.status, "NOERROR");
+        assert_eq!(resolution.server, Some(SocketAddr::from((Ipv4Addr::LOCALHOST, port))));
+        assert_eq!(resolution.transport, DnsTransport::Tcp);
+        assert_eq!(resolution.answers.len(), 1);
+        assert_eq!(resolution.answers[0].data, "192.0.2.1");
+    }
+
+    #[test]
+    fn parse_socket_table_keeps_only_listening_sockets() {
+        let tcp = "\
+  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:0CEA 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 1001 1 0000000000000000 100 0 0 10 0
+   1: 0F02000A:0016 0202000A:D431 01 00000000:00000000 02:0009B6A8 00000000     0        0 1002 4 0000000000000000 20 4 30 10 -1
+";
+        let sockets = parse_socket_table(tcp, SocketProtocol::Tcp).unwrap();
+        assert_eq!(sockets.len(), 1);
+        assert_eq!(sockets[0].address, IpAddr::from(Ipv4Addr::LOCALHOST));
+        assert_eq!(sockets[0].port, 3306);
+        assert_eq!(sockets[0].inode, 1001);
+
+        let udp6 = "\
+  sl  local_address                         remote_address                        st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode ref pointer drops
+  10: 00000000000000000000000000000000:14E9 00000000000000000000000000000000:0000 07 00000000:00000000 00:00000000 00000000   104        0 2001 2 0000000000000000 0
+  11: 00000000000000000000000001000000:A1B2 00000000000000000000000001000000:0035 07 00000000:00000000 00:00000000 00000000  1000        0 2002 2 0000000000000000 0
+  12: 00000000000000000000000001000000:C3D4 00000000000000000000000001000000:0035 01 00000000:00000000 00:00000000 00000000  1000        0 2003 2 0000000000000000 0
+";
+        let sockets = parse_socket_table(udp6, SocketProtocol::Udp).unwrap();
+        assert_eq!(sockets.len(), 1);
+        assert_eq!(sockets[0].address, IpAddr::from(Ipv6Addr::UNSPECIFIED));
+        assert_eq!(sockets[0].port, 5353);
+        assert_eq!(sockets[0].protocol, SocketProtocol::Udp);
+    }
+
+    #[test]
+    fn parse_socket_table_rejects_malformed_lines() {
+        let header = "  sl  local_address rem_address   st\n";
+        assert!(parse_socket_table(&format!("{}   0: 0100007F:0CEA 00000000:0000 0A\n", header), SocketProtocol::Tcp).is_err());
+        assert!(parse_socket_table(
+            &format!("{}   0: 0100007F 00000000:0000 0A 0 0 0 0 0 1001\n", header),
+            SocketProtocol::Tcp,
+        ).is_err());
+    }
+
+    #[test]
+    fn parse_proc_address_decodes_host_byte_order() {
+        assert_eq!(parse_proc_address("0100007F").unwrap(), IpAddr::from(Ipv4Addr::LOCALHOST));
+        assert_eq!(parse_proc_address("00000000000000000000000001000000").unwrap(), IpAddr::from(Ipv6Addr::LOCALHOST));
+        assert!(parse_proc_address("7F").is_err());
+    }
+
+    #[test]
+    fn parse_arp_table_reads_flags_and_hardware_addresses() {
+        let arp = "\
+IP address       HW type     Flags       HW address            Mask     Device
+192.168.1.1      0x1         0x2         aa:bb:cc:dd:ee:ff     *        eth0
+192.168.1.7      0x1         0x0         00:00:00:00:00:00     *        eth0
+10.0.0.1         0x1         0x6         11:22:33:44:55:66     *        wlan0
+";
+        let neighbors = parse_arp_table(arp).unwrap();
+
+        assert_eq!(neighbors.len(), 3);
+        assert_eq!(neighbors[0], Neighbor {
+            ip: IpAddr::from(Ipv4Addr::new(192, 168, 1, 1)),
+            mac: Some("aa:bb:cc:dd:ee:ff".to_string()),
+            interface: "eth0".to_string(),
+            state: "complete".to_string(),
+        });
+        assert_eq!((neighbors[1].mac.as_deref(), neighbors[1].state.as_str()), (None, "incomplete"));
+        assert_eq!((neighbors[2].interface.as_str(), neighbors[2].state.as_str()), ("wlan0", "permanent"));
+    }
+
+    #[test]
+    fn dedup_sockets_merges_sockets_on_the_same_address_and_port() {
+        let socket = |protocol, address: &str, port, pid, inode| ListeningSocket {
+            protocol,
+            address: address.parse().unwrap(),
+            port,
+            pid,
+            process: None,
+            inode,
+        };
+        let mut sockets = vec![
+            socket(SocketProtocol::Tcp, "0.0.0.0", 443, None, 1),
+            socket(SocketProtocol::Tcp, "0.0.0.0", 443, Some(42), 2),
+            socket(SocketProtocol::Tcp, "::", 443, Some(42), 3),
+            socket(SocketProtocol::Udp, "0.0.0.0", 443, Some(43), 4),
+            socket(SocketProtocol::Tcp, "0.0.0.0", 22, Some(7), 5),
+            socket(SocketProtocol::Tcp, "0.0.0.0", 443, Some(42), 6),
+        ];
+
+        dedup_sockets(&mut sockets);
+
+        let keys = sockets
+            .iter()
+            .map(|socket| (socket.protocol, socket.address.to_string(), socket.port, socket.pid))
+            .collect::<Vec<_>>();
+        assert_eq!(keys, [
+            (SocketProtocol::Tcp, "0.0.0.0".to_string(), 22, Some(7)),
+            (SocketProtocol::Tcp, "0.0.0.0".to_string(), 443, Some(42)),
+            (SocketProtocol::Tcp, "::".to_string(), 443, Some(42)),
+            (SocketProtocol::Udp, "0.0.0.0".to_string(), 443, Some(43)),
+        ]);
+    }
+
+    #[test]
+    fn parse_arp_table_rejects_malformed_lines() {
+        let header = "IP address       HW type     Flags       HW address            Mask     Device\n";
+        assert!(parse_arp_table(&format!("{}192.168.1.1 0x1 0x2\n", header)).is_err());
+        assert!(parse_arp_table(&format!("{}192.168.1.1 0x1 0xZ aa:bb:cc:dd:ee:ff * eth0\n", header)).is_err());
+        assert!(parse_arp_table(&format!("{}not-an-ip 0x1 0x2 aa:bb:cc:dd:ee:ff * eth0\n", header)).is_err());
+        assert_eq!(parse_arp_table(header).unwrap(), Vec::new());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn neighbor_from_message_skips_noarp_entries_and_unresolved_macs() {
+        use netlink_packet_route::{constants::*, neighbour::Nla, NeighbourMessage};
+
+        let message = |ip: &[u8], mac: &[u8], state: u16| {
+            let mut message = NeighbourMessage::default();
+            message.header.ifindex = 2;
+            message.header.state = state;
+            message.nlas = vec![Nla::Destination(ip.to_vec()), Nla::LinkLocalAddress(mac.to_vec())];
+            message
+        };
+        let names = HashMap::from([(1, "lo".to_string()), (2, "eth0".to_string())]);
+
+        let neighbor = neighbor_from_message(&message(&[192, 168, 1, 1], &[0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff], NUD_REACHABLE), &names).unwrap();
+        assert_eq!(neighbor.ip, IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1)));
+        assert_eq!(neighbor.mac.as_deref(), Some("aa:bb:cc:dd:ee:ff"));
+        assert_eq!((neighbor.interface.as_str(), neighbor.state.as_str()), ("eth0", "reachable"));
+
+        let neighbor = neighbor_from_message(&message(&[192, 168, 1, 2], &[0; 6], NUD_INCOMPLETE), &names).unwrap();
+        assert_eq!((neighbor.mac, neighbor.state.as_str()), (None, "incomplete"));
+
+        let mut loopback = message(&[0, 0, 0, 0], &[0; 6], NUD_NOARP);
+        loopback.header.ifindex = 1;
+        assert_eq!(neighbor_from_message(&loopback, &names), None);
+        let multicast = message(&Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 0x16).octets(), &[0x33, 0x33, 0, 0, 0, 0x16], NUD_NOARP);
+        assert_eq!(neighbor_from_message(&multicast, &names), None);
+
+        let mut unknown = message(&[], &[], NUD_STALE);
+        unknown.nlas.clear();
+        assert_eq!(neighbor_from_message(&unknown, &names), None);
+    }
+
+    #[tokio::test]
+    async fn explain_does_not_answer_the_dns_step_from_the_hosts_file() {
+        let directory = tempfile::tempdir().unwrap();
+        let hosts_path = directory.path().join("hosts");
+        let nsswitch_path = directory.path().join("nsswitch.conf");
+        std::fs::write(&hosts_path, "192.0.2.9 only-in-hosts.test\n").unwrap();
+        std::fs::write(&nsswitch_path, "hosts: dns files\n").unwrap();
+        let hosts = hosts::read_hosts(&hosts_path, &nsswitch_path).unwrap();
+        let server = udp_stand_in(nxdomain_response).await;
+
+        let steps = explain_with("only-in-hosts.test", RecordKind::A, &hosts, &[server]).await.unwrap();
+
+        assert_eq!(steps.len(), 2);
+        assert_eq!((steps[0].source.as_str(), steps[0].server), ("dns", Some(server)));
+        assert_eq!((steps[0].outcome, steps[0].detail.as_deref()), (StepOutcome::NoMatch, Some("NXDOMAIN")));
+        assert_eq!((steps[1].source.as_str(), steps[1].server), ("files", None));
+        assert_eq!((steps[1].outcome, steps[1].detail.as_deref()), (StepOutcome::Answered, Some("192.0.2.9")));
+    }
+
+    #[tokio::test]
+    async fn explain_does_not_attribute_localhost_to_a_dns_server() {
+        let hosts = hosts::Hosts { nsswitch: vec!["dns".to_string()], entries: Vec::new() };
+        let server = udp_stand_in(stand_in_response).await;
+
+        let steps = explain_with("localhost", RecordKind::A, &hosts, &[server]).await.unwrap();
+
+        assert_eq!(steps.len(), 1);
+        assert_eq!((steps[0].server, steps[0].outcome), (None, StepOutcome::NotInspected));
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt::{Display, Formatter};
+use anyhow::Result;
+use clap::ValueEnum;
+use colored::*;
+use serde::{Deserialize, Serialize};
+use sysinfo::{PidExt, ProcessExt, ProcessRefreshKind, RefreshKind, System, SystemExt, UserExt};
+use crate::format::human_readable_size;
+
+/// 进程的排序方式
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Eq, Serialize, ValueEnum)]
+pub enum ProcessSort {
+    /// 按采样期间的CPU使用率排序
+    #[clap(name = "cpu")]
+    Cpu,
+
+    /// 按常驻内存排序
+    #[clap(name = "mem")]
+    Mem,
+}
+
+/// 一个进程
+#[derive(Serialize, Debug)]
+pub struct Process {
+    pub pid: u32,
+
+    /// 进程名称
+    pub name: String,
+
+    /// 进程所属的用户，无法确定时为空
+    pub user: Option<String>,
+
+    /// 采样期间的CPU使用率，单位为单个核心的百分比，多线程进程可能超过100
+    pub cpu_usage: f32,
+
+    /// 常驻内存
+    pub rss_bytes: u64,
+
+    /// 完整的命令行，内核线程没有命令行
+    pub command: String,
+}
+
+impl Display for Process {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // 命令行参数可能包含换行，文本输出中保持每个进程一行
+        let command = if self.command.is_empty() {
+            format!("[{}]", self.name)
+        } else {
+            self.command.split_whitespace().collect::<Vec<_>>().join(" ")
+        };
+        write!(
+            f,
+            "{}\t{}\t{}\t{}%\t{}\t{}",
+            self.pid.to_string().bold(),
+            self.name.cyan(),
+            self.user.as_deref().unwrap_or("-"),
+            format!("{:.1}", self.cpu_usage).green(),
+            human_readable_size(self.rss_bytes).yellow(),
+            command,
+        )
+    }
+}
+
+/// 返回资源占用最高的 `top` 个进程。
+/// CPU使用率需要两次采样，因此会等待 `MINIMUM_CPU_UPDATE_INTERVAL`。
+pub async fn processes(top: usize, sort: ProcessSort) -> Result<Vec<Process>> {
+    let refresh_kind = RefreshKind::new()
+        .with_processes(ProcessRefreshKind::new().with_cpu().with_user())
+        .with_users_list();
+    let mut system = System::new_with_specifics(refresh_kind);
+    tokio::time::sleep(System::MINIMUM_CPU_UPDATE_INTERVAL).await;
+    system.refresh_processes_specifics(ProcessRefreshKind::new().with_cpu());
+
+    let processes = system
+        .processes()
+        .values()
+        .map(|process| Process {
+            pid: process.pid().as_u32(),
+            name: process.name().to_string(),
+            user: process
+                .user_id()
+                .and_then(|uid| system.get_user_by_id(uid))
+                .map(|user| user.name().to_string()),
+            cpu_usage: process.cpu_usage(),
+            rss_bytes: process.memory(),
+            command: process.cmd().join(" "),
+        })
+        .collect::<Vec<_>>();
+
+    Ok(top_processes(processes, top, sort))
+}
+
+/// 按 `sort` 从高到低排序并保留前 `top` 个进程。
+/// 主键相同时按另一项资源排序，两者都相同时按PID排序，使结果稳定。
+pub fn top_processes(mut processes: Vec<Process>, top: usize, sort: ProcessSort) -> Vec<Process> {
+    match sort {
+        ProcessSort::Cpu => processes.sort_by(|a, b| {
+            b.cpu_usage.total_cmp(&a.cpu_usage).then(b.rss_bytes.cmp(&a.rss_bytes)).then(a.pid.cmp(&b.pid))
+        }),
+        ProcessSort::Mem => processes.sort_by(|a, b| {
+            b.rss_bytes.cmp(&a.rss_bytes).then(b.cpu_usage.total_cmp(&a.cpu_usage)).then(a.pid.cmp(&b.pid))
+        }),
+    }
+    processes.truncate(top);
+    processes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, cpu_usage: f32, rss_bytes: u64) -> Process {
+        Process {
+            pid,
+            name: format!("process{}", pid),
+            user: None,
+            cpu_usage,
+            rss_bytes,
+            command: String::new(),
+        }
+    }
+
+    fn pids(processes: &[Process]) -> Vec<u32> {
+        processes.iter().map(|process| process.pid).collect()
+    }
+
+    fn sample() -> Vec<Process> {
+        vec![
+            process(1, 0.5, 4096),
+            process(2, 50.0, 1024),
+            process(3, 12.5, 8192),
+            process(4, 120.0, 2048),
+        ]
+    }
+
+    #[test]
+    fn top_processes_orders_by_cpu_or_memory() {
+        assert_eq!(pids(&top_processes(sample(), 10, ProcessSort::Cpu)), [4, 2, 3, 1]);
+        assert_eq!(pids(&top_processes(sample(), 10, ProcessSort::Mem)), [3, 1, 4, 2]);
+    }
+
+    #[test]
+    fn top_processes_breaks_ties_by_the_other_resource_then_pid() {
+        let processes = vec![process(7, 10.0, 100), process(5, 10.0, 300), process(6, 10.0, 300), process(8, 0.0, 300)];
+        assert_eq!(pids(&top_processes(processes, 10, ProcessSort::Cpu)), [5, 6, 7, 8]);
+
+        let processes = vec![process(7, 10.0, 100), process(5, 10.0, 300), process(6, 10.0, 300), process(8, 0.0, 300)];
+        assert_eq!(pids(&top_processes(processes, 10, ProcessSort::Mem)), [5, 6, 8, 7]);
+    }
+
+    #[test]
+    fn top_processes_keeps_only_the_first_n() {
+        assert_eq!(pids(&top_processes(sample(), 2, ProcessSort::Cpu)), [4, 2]);
+        assert_eq!(pids(&top_processes(sample(), 1, ProcessSort::Mem)), [3]);
+        assert_eq!(top_processes(sample(), 10, ProcessSort::Cpu).len(), 4);
+        assert!(top_processes(Vec::new(), 3, ProcessSort::Cpu).is_empty());
+    }
+}
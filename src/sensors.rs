@@ -0,0 +1,322 @@
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use anyhow::Result;
+use colored::*;
+use serde::Serialize;
+use sysinfo::{ComponentExt, RefreshKind, System, SystemExt};
+use tokio::task::spawn_blocking;
+
+/// 硬件监控设备目录
+pub const SYS_CLASS_HWMON: &str = "/sys/class/hwmon";
+
+/// 温度区域目录
+pub const SYS_CLASS_THERMAL: &str = "/sys/class/thermal";
+
+/// 一个温度或风扇传感器
+#[derive(Serialize, Debug)]
+pub struct Sensor {
+    /// 数据来源：hwmon、thermal 或 sysinfo
+    pub source: String,
+
+    /// 芯片或温度区域的名称，例如 coretemp 或 acpitz
+    pub chip: String,
+
+    /// 传感器标签，例如 Package id 0 或 fan1
+    pub label: String,
+
+    /// 当前温度，单位为摄氏度
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature_celsius: Option<f32>,
+
+    /// 最高温度，单位为摄氏度
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_celsius: Option<f32>,
+
+    /// 临界温度，单位为摄氏度
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub critical_celsius: Option<f32>,
+
+    /// 风扇转速，单位为RPM
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fan_rpm: Option<u32>,
+}
+
+impl Display for Sensor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\t{}", self.chip.bold(), self.label)?;
+
+        if let Some(temperature) = self.temperature_celsius {
+            let text = format!("{:.1}°C", temperature);
+            // 按与RAM相同的阈值，根据距临界温度的远近着色
+            let colored = match self.critical_celsius {
+                Some(critical) if critical > 0.0 && temperature / critical > 0.9 => text.red(),
+                Some(critical) if critical > 0.0 && temperature / critical > 0.7 => text.yellow(),
+                Some(_) => text.green(),
+                None => text.normal(),
+            };
+            write!(f, "\t{}", colored)?;
+
+            if let Some(max) = self.max_celsius {
+                write!(f, "\tmax {:.1}°C", max)?;
+            }
+            if let Some(critical) = self.critical_celsius {
+                write!(f, "\tcritical {:.1}°C", critical)?;
+            }
+        }
+
+        if let Some(rpm) = self.fan_rpm {
+            write!(f, "\t{} RPM", rpm.to_string().cyan())?;
+        }
+        Ok(())
+    }
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    std::fs::read_to_string(path).ok().map(|contents| contents.trim().to_string())
+}
+
+/// 读取以千分之一摄氏度为单位的温度
+fn read_millidegrees(path: &Path) -> Option<f32> {
+    read_trimmed(path)?.parse::<i64>().ok().map(|millidegrees| millidegrees as f32 / 1000.0)
+}
+
+/// 列出目录中 `*_input` 属性的名称，不含后缀，按名称排序
+fn inputs(directory: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(directory) else { return Vec::new() };
+    let mut inputs = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().to_str()?.strip_suffix("_input").map(str::to_string))
+        .collect::<Vec<_>>();
+    inputs.sort();
+    inputs
+}
+
+/// 读取 `root` 下每个 hwmon 设备的 `temp*_input` 和 `fan*_input`。
+/// 较旧的内核把属性放在设备的 `device` 子目录中，只有设备目录本身没有属性时才读取该子目录。
+pub fn hwmon_sensors(root: &Path) -> Vec<Sensor> {
+    let Ok(devices) = std::fs::read_dir(root) else { return Vec::new() };
+
+    let mut devices = devices.filter_map(|entry| entry.ok()).map(|entry| entry.path()).collect::<Vec<_>>();
+    devices.sort();
+
+    let mut sensors = Vec::new();
+    for device in devices {
+        let mut directory = device.clone();
+        let mut names = inputs(&directory);
+        if names.is_empty() {
+            directory = device.join("device");
+            names = inputs(&directory);
+        }
+
+        let chip = read_trimmed(&device.join("name"))
+            .or_else(|| read_trimmed(&directory.join("name")))
+            .unwrap_or_else(|| device.file_name().unwrap_or_default().to_string_lossy().to_string());
+
+        for input in names {
+            let attribute = |suffix: &str| directory.join(format!("{}_{}", input, suffix));
+            let label = read_trimmed(&attribute("label")).unwrap_or_else(|| input.clone());
+
+            if input.starts_with("temp") {
+                let Some(temperature) = read_millidegrees(&attribute("input")) else { continue };
+                sensors.push(Sensor {
+                    source: "hwmon".to_string(),
+                    chip: chip.clone(),
+                    label,
+                    temperature_celsius: Some(temperature),
+                    max_celsius: read_millidegrees(&attribute("max")),
+                    critical_celsius: read_millidegrees(&attribute("crit")),
+                    fan_rpm: None,
+                });
+            } else if input.starts_with("fan") {
+                let Some(rpm) = read_trimmed(&attribute("input")).and_then(|rpm| rpm.parse().ok()) else { continue };
+                sensors.push(Sensor {
+                    source: "hwmon".to_string(),
+                    chip: chip.clone(),
+                    label,
+                    temperature_celsius: None,
+                    max_celsius: None,
+                    critical_celsius: None,
+                    fan_rpm: Some(rpm),
+                });
+            }
+        }
+    }
+    sensors
+}
+
+/// 读取 `root` 下的 `thermal_zone*`，临界温度取自类型为 critical 的触发点
+pub fn thermal_sensors(root: &Path) -> Vec<Sensor> {
+    let Ok(entries) = std::fs::read_dir(root) else { return Vec::new() };
+
+    let mut zones = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("thermal_zone"))
+        .map(|entry| entry.path())
+        .collect::<Vec<_>>();
+    zones.sort();
+
+    zones
+        .into_iter()
+        .filter_map(|zone| {
+            let temperature = read_millidegrees(&zone.join("temp"))?;
+            let critical = (0..)
+                .map_while(|trip| read_trimmed(&zone.join(format!("trip_point_{}_type", trip))).map(|kind| (trip, kind)))
+                .find(|(_, kind)| kind == "critical")
+                .and_then(|(trip, _)| read_millidegrees(&zone.join(format!("trip_point_{}_temp", trip))));
+
+            Some(Sensor {
+                source: "thermal".to_string(),
+                chip: read_trimmed(&zone.join("type")).unwrap_or_default(),
+                label: zone.file_name()?.to_string_lossy().to_string(),
+                temperature_celsius: Some(temperature),
+                max_celsius: None,
+                critical_celsius: critical,
+                fan_rpm: None,
+            })
+        })
+        .collect()
+}
+
+/// 合并 hwmon 和温度区域的传感器。
+/// 注册了 hwmon 的温度区域（例如 acpitz）会以同名芯片出现在 hwmon 中，此类温度区域只保留 hwmon 的一份；
+/// hwmon 名称中的 '-' 会被内核替换为 '_'，比较前做同样的替换。
+pub fn merge_sensors(mut hwmon: Vec<Sensor>, thermal: Vec<Sensor>) -> Vec<Sensor> {
+    let hwmon_name = |chip: &str| chip.replace('-', "_");
+    let thermal = thermal
+        .into_iter()
+        .filter(|zone| !hwmon.iter().any(|sensor| hwmon_name(&sensor.chip) == hwmon_name(&zone.chip)))
+        .collect::<Vec<_>>();
+    hwmon.extend(thermal);
+    hwmon
+}
+
+/// 列出温度和风扇传感器。
+/// Linux 上直接读取 hwmon 和温度区域；两者都没有数据时（例如其他操作系统），使用 sysinfo 的组件。
+pub async fn sensors() -> Result<Vec<Sensor>> {
+    let mut sensors = spawn_blocking(|| {
+        merge_sensors(hwmon_sensors(Path::new(SYS_CLASS_HWMON)), thermal_sensors(Path::new(SYS_CLASS_THERMAL)))
+    }).await?;
+
+    if sensors.is_empty() {
+        let system = System::new_with_specifics(RefreshKind::new().with_components_list());
+        sensors = system
+            .components()
+            .iter()
+            .map(|component| Sensor {
+                source: "sysinfo".to_string(),
+                chip: component.label().to_string(),
+                label: component.label().to_string(),
+                temperature_celsius: Some(component.temperature()),
+                max_celsius: Some(component.max()).filter(|max| *max > 0.0),
+                critical_celsius: component.critical(),
+                fan_rpm: None,
+            })
+            .collect();
+    }
+
+    Ok(sensors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write(path: &Path, contents: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    #[test]
+    fn hwmon_sensors_reads_labels_limits_and_fans() {
+        let root = tempfile::tempdir().unwrap();
+        let coretemp = root.path().join("hwmon0");
+        write(&coretemp.join("name"), "coretemp\n");
+        write(&coretemp.join("temp1_input"), "45000\n");
+        write(&coretemp.join("temp1_label"), "Package id 0\n");
+        write(&coretemp.join("temp1_max"), "80000\n");
+        write(&coretemp.join("temp1_crit"), "100000\n");
+        write(&coretemp.join("temp2_input"), "41500\n");
+        let fans = root.path().join("hwmon1");
+        write(&fans.join("name"), "nct6775\n");
+        write(&fans.join("fan1_input"), "1200\n");
+
+        let sensors = hwmon_sensors(root.path());
+        assert_eq!(sensors.len(), 3);
+
+        assert_eq!(sensors[0].chip, "coretemp");
+        assert_eq!(sensors[0].label, "Package id 0");
+        assert_eq!(sensors[0].temperature_celsius, Some(45.0));
+        assert_eq!(sensors[0].max_celsius, Some(80.0));
+        assert_eq!(sensors[0].critical_celsius, Some(100.0));
+
+        assert_eq!(sensors[1].label, "temp2");
+        assert_eq!(sensors[1].temperature_celsius, Some(41.5));
+        assert_eq!(sensors[1].critical_celsius, None);
+
+        assert_eq!(sensors[2].chip, "nct6775");
+        assert_eq!(sensors[2].label, "fan1");
+        assert_eq!(sensors[2].fan_rpm, Some(1200));
+        assert_eq!(sensors[2].temperature_celsius, None);
+    }
+
+    #[test]
+    fn hwmon_sensors_reads_the_device_directory_only_as_a_fallback() {
+        let root = tempfile::tempdir().unwrap();
+        let modern = root.path().join("hwmon0");
+        write(&modern.join("name"), "k10temp\n");
+        write(&modern.join("temp1_input"), "50000\n");
+        write(&modern.join("device/temp1_input"), "50000\n");
+        let legacy = root.path().join("hwmon1");
+        write(&legacy.join("device/name"), "it87\n");
+        write(&legacy.join("device/temp1_input"), "30000\n");
+        let unnamed = root.path().join("hwmon2");
+        write(&unnamed.join("temp1_input"), "20000\n");
+
+        let sensors = hwmon_sensors(root.path());
+        assert_eq!(sensors.len(), 3);
+        assert_eq!(sensors[0].chip, "k10temp");
+        assert_eq!(sensors[1].chip, "it87");
+        assert_eq!(sensors[1].temperature_celsius, Some(30.0));
+        assert_eq!(sensors[2].chip, "hwmon2");
+    }
+
+    #[test]
+    fn thermal_sensors_reads_the_critical_trip_point() {
+        let root = tempfile::tempdir().unwrap();
+        let zone = root.path().join("thermal_zone0");
+        write(&zone.join("type"), "x86_pkg_temp\n");
+        write(&zone.join("temp"), "55000\n");
+        write(&zone.join("trip_point_0_type"), "passive\n");
+        write(&zone.join("trip_point_0_temp"), "90000\n");
+        write(&zone.join("trip_point_1_type"), "critical\n");
+        write(&zone.join("trip_point_1_temp"), "105000\n");
+        write(&root.path().join("cooling_device0/type"), "Processor\n");
+
+        let sensors = thermal_sensors(root.path());
+        assert_eq!(sensors.len(), 1);
+        assert_eq!(sensors[0].chip, "x86_pkg_temp");
+        assert_eq!(sensors[0].label, "thermal_zone0");
+        assert_eq!(sensors[0].temperature_celsius, Some(55.0));
+        assert_eq!(sensors[0].critical_celsius, Some(105.0));
+    }
+
+    #[test]
+    fn merge_sensors_drops_thermal_zones_already_in_hwmon() {
+        let hwmon_root = tempfile::tempdir().unwrap();
+        write(&hwmon_root.path().join("hwmon0/name"), "acpitz\n");
+        write(&hwmon_root.path().join("hwmon0/temp1_input"), "27800\n");
+        write(&hwmon_root.path().join("hwmon1/name"), "iwlwifi_1\n");
+        write(&hwmon_root.path().join("hwmon1/temp1_input"), "40000\n");
+
+        let thermal_root = tempfile::tempdir().unwrap();
+        for (zone, kind) in [("thermal_zone0", "acpitz"), ("thermal_zone1", "iwlwifi-1"), ("thermal_zone2", "x86_pkg_temp")] {
+            write(&thermal_root.path().join(zone).join("type"), kind);
+            write(&thermal_root.path().join(zone).join("temp"), "27800");
+        }
+
+        let sensors = merge_sensors(hwmon_sensors(hwmon_root.path()), thermal_sensors(thermal_root.path()));
+        let chips = sensors.iter().map(|sensor| (sensor.source.as_str(), sensor.chip.as_str())).collect::<Vec<_>>();
+        assert_eq!(chips, [("hwmon", "acpitz"), ("hwmon", "iwlwifi_1"), ("thermal", "x86_pkg_temp")]);
+    }
+}
@@ -20,8 +20,8 @@ pub async fn list_disks() -> Result<Vec<DiskInfo>> {
             let name = disk
                 .name()
                 .to_str()
-                .ok_or_else(|| "unknown")
-                .map_err(|e| Error::msg(e))?;
+                .ok_or("unknown")
+                .map_err(Error::msg)?;
 
             Ok(DiskInfo{
                 name:name.to_string(),
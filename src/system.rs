@@ -1,6 +1,8 @@
 use sysinfo::{CpuExt, CpuRefreshKind, RefreshKind, System, SystemExt};
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
-use anyhow::Result;
+use std::path::Path;
+use anyhow::{anyhow, Context, Result};
 use colored::*;
 use serde::Serialize;
 use crate::format::human_readable_size;
@@ -35,33 +37,380 @@ pub struct Cpu {
     // CPU的品牌
     pub brand: String,
 
-    // CPU核心数量
-    pub core_count: usize,
+    // CPU的厂商ID，例如 GenuineIntel 或 AuthenticAMD
+    pub vendor_id: String,
+
+    // 物理核心数量，无法确定时为空
+    pub physical_core_count: Option<usize>,
+
+    // 逻辑核心数量，包括超线程。沿用此前的字段名 core_count
+    #[serde(rename = "core_count")]
+    pub logical_core_count: usize,
 
     // CPU的频率，单位为MHz
-    pub frequency: u64
+    pub frequency: u64,
+
+    // 采样期间所有核心的平均使用率，单位为百分比
+    pub usage: f32,
+
+    // 每个逻辑核心的详细信息，仅在请求时填充
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub cores: Vec<CpuCore>,
+
+    // 指令集扩展、缓存和NUMA拓扑，仅在请求时填充
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<CpuFeatures>,
 }
 
 impl Display for Cpu {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}, {} cores running at {} GHz", self.brand.bold(), self.core_count.to_string().cyan(), self.frequency.to_string().green())
+        let cores = match self.physical_core_count {
+            Some(physical) => format!("{} cores, {} threads", physical.to_string().cyan(), self.logical_core_count.to_string().cyan()),
+            None => format!("{} cores", self.logical_core_count.to_string().cyan()),
+        };
+        write!(
+            f,
+            "{} ({}), {} running at {} MHz, {}% in use",
+            self.brand.bold(),
+            self.vendor_id,
+            cores,
+            self.frequency.to_string().green(),
+            usage_colored(self.usage.into()),
+        )?;
+
+        for core in &self.cores {
+            write!(f, "\n{}", core)?;
+        }
+        if let Some(features) = &self.features {
+            write!(f, "\n{}", features)?;
+        }
+        Ok(())
+    }
+}
+
+/// 一个逻辑核心
+#[derive(Serialize)]
+pub struct CpuCore {
+
+    // 核心名称，例如 cpu0
+    pub name: String,
+
+    // 核心的频率，单位为MHz
+    pub frequency: u64,
+
+    // 采样期间的使用率，单位为百分比
+    pub usage: f32,
+}
+
+impl Display for CpuCore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\t{} MHz\t{}%", self.name.bold(), self.frequency, usage_colored(self.usage.into()))
     }
 }
 
-pub async fn cpus() -> Result<Cpu> {
-    let mut system = System::new_with_specifics(RefreshKind::new().with_cpu(CpuRefreshKind::new().with_frequency()));
+/// 为百分比形式的使用率着色，CPU、负载和RAM共用这组阈值
+fn usage_colored(usage: f64) -> ColoredString {
+    colored_by_usage(format!("{:.1}", usage), usage)
+}
+
+/// 按使用率为文本着色：超过90%为红色，超过70%为黄色
+fn colored_by_usage(text: String, usage: f64) -> ColoredString {
+    match usage {
+        _ if usage > 90.0 => text.red(),
+        _ if usage > 70.0 => text.yellow(),
+        _ => text.green(),
+    }
+}
+
+/// 返回系统的CPU。使用率需要两次采样，因此会等待 `MINIMUM_CPU_UPDATE_INTERVAL`。
+/// `per_core` 为真时附加每个逻辑核心的频率和使用率，`features` 为真时附加指令集扩展和缓存拓扑。
+pub async fn cpus(per_core: bool, features: bool) -> Result<Cpu> {
+    let mut system = System::new_with_specifics(RefreshKind::new().with_cpu(CpuRefreshKind::everything()));
+    tokio::time::sleep(System::MINIMUM_CPU_UPDATE_INTERVAL).await;
     system.refresh_cpu();
 
     let cpus = system.cpus();
-    let reference_cpu = cpus.get(0).unwrap();
+    let reference_cpu = cpus.first().ok_or_else(|| anyhow!("no CPU found"))?;
+
+    let cores = if per_core {
+        cpus.iter()
+            .map(|cpu| CpuCore {
+                name: cpu.name().to_string(),
+                frequency: cpu.frequency(),
+                usage: cpu.cpu_usage(),
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let features = if features { Some(cpu_features().await?) } else { None };
 
     Ok(Cpu{
         brand: reference_cpu.brand().to_string(),
-        core_count:cpus.len(),
-        frequency:reference_cpu.frequency(),
+        vendor_id: reference_cpu.vendor_id().to_string(),
+        physical_core_count: system.physical_core_count(),
+        logical_core_count: cpus.len(),
+        frequency: reference_cpu.frequency(),
+        usage: system.global_cpu_info().cpu_usage(),
+        cores,
+        features,
     })
 }
 
+/// 内核CPU信息文件
+pub const PROC_CPUINFO: &str = "/proc/cpuinfo";
+
+/// CPU拓扑所在的目录
+pub const SYS_DEVICES_SYSTEM: &str = "/sys/devices/system";
+
+/// 报告的指令集扩展：`/proc/cpuinfo` 中的标志及其常用名称。
+/// x86 的标志位于 `flags` 行，ARM 的位于 `Features` 行。
+pub const CPU_EXTENSIONS: [(&str, &str); 40] = [
+    ("sse", "SSE"),
+    ("sse2", "SSE2"),
+    ("pni", "SSE3"),
+    ("ssse3", "SSSE3"),
+    ("sse4_1", "SSE4.1"),
+    ("sse4_2", "SSE4.2"),
+    ("popcnt", "POPCNT"),
+    ("avx", "AVX"),
+    ("avx2", "AVX2"),
+    ("fma", "FMA"),
+    ("f16c", "F16C"),
+    ("bmi1", "BMI1"),
+    ("bmi2", "BMI2"),
+    ("adx", "ADX"),
+    ("avx512f", "AVX-512F"),
+    ("avx512cd", "AVX-512CD"),
+    ("avx512bw", "AVX-512BW"),
+    ("avx512dq", "AVX-512DQ"),
+    ("avx512vl", "AVX-512VL"),
+    ("avx512_vnni", "AVX-512-VNNI"),
+    ("avx512_bf16", "AVX-512-BF16"),
+    ("avx512_fp16", "AVX-512-FP16"),
+    ("avx_vnni", "AVX-VNNI"),
+    ("amx_tile", "AMX"),
+    ("aes", "AES"),
+    ("vaes", "VAES"),
+    ("pclmulqdq", "PCLMULQDQ"),
+    ("sha_ni", "SHA"),
+    ("rdrand", "RDRAND"),
+    ("rdseed", "RDSEED"),
+    ("neon", "NEON"),
+    ("asimd", "NEON"),
+    ("pmull", "PMULL"),
+    ("sha1", "SHA1"),
+    ("sha2", "SHA2"),
+    ("sha3", "SHA3"),
+    ("crc32", "CRC32"),
+    ("atomics", "LSE"),
+    ("sve", "SVE"),
+    ("sve2", "SVE2"),
+];
+
+/// 各 x86-64 微架构级别在前一级别基础上要求的标志
+const X86_64_LEVELS: [(u8, &[&str]); 3] = [
+    (2, &["cx16", "lahf_lm", "popcnt", "pni", "sse4_1", "sse4_2", "ssse3"]),
+    (3, &["avx", "avx2", "bmi1", "bmi2", "f16c", "fma", "abm", "movbe", "xsave"]),
+    (4, &["avx512f", "avx512bw", "avx512cd", "avx512dq", "avx512vl"]),
+];
+
+/// 一个CPU缓存
+#[derive(Serialize)]
+pub struct CpuCache {
+
+    // 缓存级别，1 表示 L1
+    pub level: u8,
+
+    // 缓存类型：Data、Instruction 或 Unified
+    pub kind: String,
+
+    pub size_bytes: u64,
+
+    // 共享该缓存的逻辑核心，例如 0-3
+    pub shared_cpus: String,
+}
+
+impl Display for CpuCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\tshared by CPUs {}",
+            format!("L{}", self.level).bold(),
+            self.kind,
+            human_readable_size(self.size_bytes).cyan(),
+            self.shared_cpus,
+        )
+    }
+}
+
+/// 一个NUMA节点
+#[derive(Serialize)]
+pub struct NumaNode {
+    pub id: u32,
+
+    // 属于该节点的逻辑核心，例如 0-3
+    pub cpus: String,
+
+    pub memory_bytes: Option<u64>,
+}
+
+impl Display for NumaNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\tCPUs {}", format!("node{}", self.id).bold(), self.cpus)?;
+        if let Some(memory) = self.memory_bytes {
+            write!(f, "\t{}", human_readable_size(memory).cyan())?;
+        }
+        Ok(())
+    }
+}
+
+/// CPU的指令集扩展和缓存拓扑
+#[derive(Serialize)]
+pub struct CpuFeatures {
+
+    // 支持的指令集扩展
+    pub extensions: Vec<String>,
+
+    // 支持的 x86-64 微架构级别，非 x86 CPU为空
+    pub x86_64_level: Option<u8>,
+
+    // 第一个逻辑核心可见的缓存
+    pub caches: Vec<CpuCache>,
+
+    pub numa_nodes: Vec<NumaNode>,
+}
+
+impl Display for CpuFeatures {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\t{}", "extensions".cyan(), self.extensions.join(" "))?;
+        if let Some(level) = self.x86_64_level {
+            write!(f, "\n{}\tx86-64-v{}", "level".cyan(), level)?;
+        }
+        for cache in &self.caches {
+            write!(f, "\n{}", cache)?;
+        }
+        for node in &self.numa_nodes {
+            write!(f, "\n{}", node)?;
+        }
+        Ok(())
+    }
+}
+
+/// 返回 `/proc/cpuinfo` 中第一个处理器的标志，以及它们是否来自 x86 的 `flags` 行
+pub fn parse_cpu_flags(contents: &str) -> (Vec<String>, bool) {
+    contents
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find_map(|(key, value)| match key.trim() {
+            "flags" => Some((value.split_whitespace().map(str::to_string).collect(), true)),
+            "Features" => Some((value.split_whitespace().map(str::to_string).collect(), false)),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+/// 按标志计算支持的最高 x86-64 微架构级别
+pub fn x86_64_level(flags: &[String]) -> u8 {
+    let mut level = 1;
+    for (next, required) in X86_64_LEVELS {
+        if !required.iter().all(|flag| flags.iter().any(|present| present == flag)) {
+            break;
+        }
+        level = next;
+    }
+    level
+}
+
+/// 解析sysfs中的缓存大小，例如 `48K`
+fn parse_cache_size(size: &str) -> Option<u64> {
+    let size = size.trim();
+    let (digits, multiplier) = match size.chars().last()? {
+        'K' => (&size[..size.len() - 1], 1024),
+        'M' => (&size[..size.len() - 1], 1024 * 1024),
+        'G' => (&size[..size.len() - 1], 1024 * 1024 * 1024),
+        _ => (size, 1),
+    };
+    digits.parse::<u64>().ok().map(|digits| digits * multiplier)
+}
+
+fn read_sys(path: &Path) -> Option<String> {
+    std::fs::read_to_string(path).ok().map(|contents| contents.trim().to_string())
+}
+
+/// 读取第一个逻辑核心的缓存
+fn cpu_caches() -> Vec<CpuCache> {
+    let cache_dir = Path::new(SYS_DEVICES_SYSTEM).join("cpu/cpu0/cache");
+    let Ok(entries) = std::fs::read_dir(cache_dir) else { return Vec::new() };
+
+    let mut caches = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("index"))
+        .filter_map(|entry| {
+            let path = entry.path();
+            Some(CpuCache {
+                level: read_sys(&path.join("level"))?.parse().ok()?,
+                kind: read_sys(&path.join("type"))?,
+                size_bytes: parse_cache_size(&read_sys(&path.join("size"))?)?,
+                shared_cpus: read_sys(&path.join("shared_cpu_list")).unwrap_or_default(),
+            })
+        })
+        .collect::<Vec<_>>();
+    caches.sort_by(|a, b| a.level.cmp(&b.level).then_with(|| a.kind.cmp(&b.kind)));
+    caches
+}
+
+/// 读取NUMA节点，内存大小取自节点 meminfo 中的 MemTotal
+fn numa_nodes() -> Vec<NumaNode> {
+    let node_dir = Path::new(SYS_DEVICES_SYSTEM).join("node");
+    let Ok(entries) = std::fs::read_dir(node_dir) else { return Vec::new() };
+
+    let mut nodes = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let id = entry.file_name().to_string_lossy().strip_prefix("node")?.parse().ok()?;
+            let path = entry.path();
+            let memory_bytes = read_sys(&path.join("meminfo")).and_then(|meminfo| {
+                meminfo.lines().find_map(|line| {
+                    let (_, value) = line.split_once("MemTotal:")?;
+                    value.trim().trim_end_matches("kB").trim().parse::<u64>().ok().map(|kib| kib * 1024)
+                })
+            });
+            Some(NumaNode { id, cpus: read_sys(&path.join("cpulist")).unwrap_or_default(), memory_bytes })
+        })
+        .collect::<Vec<_>>();
+    nodes.sort_by_key(|node| node.id);
+    nodes
+}
+
+/// 返回CPU的指令集扩展、缓存和NUMA节点
+///
+/// # Errors
+///
+/// 如果无法读取 `/proc/cpuinfo`。
+pub async fn cpu_features() -> Result<CpuFeatures> {
+    tokio::task::spawn_blocking(|| {
+        let cpuinfo = std::fs::read_to_string(PROC_CPUINFO)
+            .with_context(|| format!("reading {} failed", PROC_CPUINFO))?;
+        let (flags, is_x86) = parse_cpu_flags(&cpuinfo);
+
+        let mut extensions = Vec::new();
+        for (flag, name) in CPU_EXTENSIONS {
+            if flags.iter().any(|present| present == flag) && !extensions.iter().any(|known| known == name) {
+                extensions.push(name.to_string());
+            }
+        }
+
+        Ok(CpuFeatures {
+            extensions,
+            x86_64_level: is_x86.then(|| x86_64_level(&flags)),
+            caches: cpu_caches(),
+            numa_nodes: numa_nodes(),
+        })
+    }).await?
+}
+
 /// 描述系统的RAM
 #[derive(Serialize)]
 pub struct Ram {
@@ -77,6 +426,25 @@ pub struct Ram {
 
     #[serde(rename = "available_ram_bytes")]
     pub available:u64,
+
+    #[serde(rename = "total_swap_bytes")]
+    pub swap_total:u64,
+
+    #[serde(rename = "used_swap_bytes")]
+    pub swap_used:u64,
+
+    #[serde(rename = "free_swap_bytes")]
+    pub swap_free:u64,
+
+    // 交换使用率是否超过 SWAP_HEAVY_USE_PERCENTAGE
+    pub swap_heavily_used:bool,
+
+    // `/proc/swaps` 中的交换设备
+    pub swap_devices:Vec<SwapDevice>,
+
+    // `/proc/meminfo` 中的内存明细，仅在请求时填充
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub breakdown:Option<MemoryBreakdown>,
 }
 
 impl Display for Ram {
@@ -85,33 +453,560 @@ impl Display for Ram {
         let used = human_readable_size(self.used);
         let used_percentage = (self.used as f64 / self.total as f64) * 100.0;
 
-        println!("used percentage: {}", used_percentage);
-
-        let (used_colored, used_percentage_colored) = match used_percentage {
-            _ if used_percentage > 90.0 => (used.red(), format!("{:.1}", used_percentage).to_string().red()),
-            _ if used_percentage > 70.0 => (used.yellow(), format!("{:.1}", used_percentage).to_string().yellow()),
-            _ => (used.green(), format!("{:.1}", used_percentage).to_string().green()),
-        };
-
         write!(
             f,
             "{} installed, {} in use ({}%)",
             total.bold(),
-            used_colored,
-            used_percentage_colored,
+            colored_by_usage(used, used_percentage),
+            usage_colored(used_percentage),
+        )?;
+
+        if self.swap_total == 0 {
+            write!(f, "\n{}", "no swap".bright_black())?;
+        } else {
+            let swap_used_percentage = (self.swap_used as f64 / self.swap_total as f64) * 100.0;
+            let swap_used = format!("{} ({:.1}%)", human_readable_size(self.swap_used), swap_used_percentage);
+            write!(
+                f,
+                "\n{} swap, {} in use",
+                human_readable_size(self.swap_total).bold(),
+                if self.swap_heavily_used { swap_used.red() } else { swap_used.green() },
+            )?;
+            if self.swap_heavily_used {
+                write!(f, " {}", "heavy swapping".red().bold())?;
+            }
+        }
+
+        for device in &self.swap_devices {
+            write!(f, "\n{}", device)?;
+        }
+        if let Some(breakdown) = &self.breakdown {
+            write!(f, "\n{}", breakdown)?;
+        }
+        Ok(())
+    }
+}
+
+/// 交换使用率超过该百分比时视为大量使用
+pub const SWAP_HEAVY_USE_PERCENTAGE: f64 = 50.0;
+
+/// 内核交换设备列表文件
+pub const PROC_SWAPS: &str = "/proc/swaps";
+
+/// 一个交换分区或交换文件
+#[derive(Serialize)]
+pub struct SwapDevice {
+
+    pub path:String,
+
+    // partition 或 file
+    pub kind:String,
+
+    pub size_bytes:u64,
+
+    pub used_bytes:u64,
+
+    pub priority:i32,
+}
+
+impl Display for SwapDevice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{} of {} in use\tpriority {}",
+            self.path.bold(),
+            self.kind,
+            human_readable_size(self.used_bytes),
+            human_readable_size(self.size_bytes),
+            self.priority,
         )
     }
 }
 
-///以RAM结构返回系统的RAM
-pub async fn ram() -> Result<Ram> {
+/// 解析 `/proc/swaps` 的内容，大小以KiB为单位
+pub fn parse_swaps(contents: &str) -> Vec<SwapDevice> {
+    contents
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            Some(SwapDevice {
+                path: fields.next()?.replace("\\040", " "),
+                kind: fields.next()?.to_string(),
+                size_bytes: fields.next()?.parse::<u64>().ok()? * 1024,
+                used_bytes: fields.next()?.parse::<u64>().ok()? * 1024,
+                priority: fields.next()?.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// 内核内存统计文件
+pub const PROC_MEMINFO: &str = "/proc/meminfo";
+
+/// `/proc/meminfo` 中的内存明细，用于解释RAM的使用量
+#[derive(Serialize)]
+pub struct MemoryBreakdown {
+
+    // 块设备缓冲区
+    pub buffers_bytes:u64,
+
+    // 页缓存，不包括交换缓存
+    pub cached_bytes:u64,
+
+    // 共享内存和 tmpfs
+    pub shared_bytes:u64,
+
+    // 匿名页，即进程的堆和栈
+    pub anonymous_bytes:u64,
+
+    // 内核 slab 分配器
+    pub slab_bytes:u64,
+
+    pub slab_reclaimable_bytes:u64,
+
+    pub slab_unreclaimable_bytes:u64,
+
+    // 等待写回磁盘的页
+    pub dirty_bytes:u64,
+
+    // 正在写回磁盘的页
+    pub writeback_bytes:u64,
+
+    // 已提交的内存，即所有进程分配的虚拟内存总量
+    pub committed_bytes:u64,
+
+    // 严格超额提交模式下可提交的内存上限
+    pub commit_limit_bytes:u64,
+
+    // 透明大页使用的匿名内存
+    pub anonymous_hugepages_bytes:u64,
+
+    // 预留的大页数量
+    pub hugepages_total:u64,
+
+    pub hugepages_free:u64,
+
+    pub hugepage_size_bytes:u64,
+}
+
+impl Display for MemoryBreakdown {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let rows = [
+            ("buffers", self.buffers_bytes),
+            ("cached", self.cached_bytes),
+            ("shared", self.shared_bytes),
+            ("anonymous", self.anonymous_bytes),
+            ("slab", self.slab_bytes),
+            ("  reclaimable", self.slab_reclaimable_bytes),
+            ("  unreclaimable", self.slab_unreclaimable_bytes),
+            ("dirty", self.dirty_bytes),
+            ("writeback", self.writeback_bytes),
+            ("committed", self.committed_bytes),
+            ("commit limit", self.commit_limit_bytes),
+            ("anon hugepages", self.anonymous_hugepages_bytes),
+        ];
+        let rows = rows
+            .iter()
+            .map(|(name, bytes)| format!("{:<16}{}", name, human_readable_size(*bytes).cyan()))
+            .collect::<Vec<String>>();
+        write!(f, "{}", rows.join("\n"))?;
+
+        write!(
+            f,
+            "\n{:<16}{} of {} free, {} each",
+            "hugepages",
+            self.hugepages_free,
+            self.hugepages_total,
+            human_readable_size(self.hugepage_size_bytes),
+        )
+    }
+}
+
+/// 解析 `/proc/meminfo`，以kB为单位的值转换为字节，没有单位的计数保持不变
+pub fn parse_meminfo(contents: &str) -> HashMap<String, u64> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            let mut fields = value.split_whitespace();
+            let value = fields.next()?.parse::<u64>().ok()?;
+            let value = if fields.next() == Some("kB") { value * 1024 } else { value };
+            Some((name.to_string(), value))
+        })
+        .collect()
+}
+
+impl From<&HashMap<String, u64>> for MemoryBreakdown {
+    fn from(meminfo: &HashMap<String, u64>) -> Self {
+        let field = |name: &str| meminfo.get(name).copied().unwrap_or_default();
+        MemoryBreakdown {
+            buffers_bytes: field("Buffers"),
+            cached_bytes: field("Cached"),
+            shared_bytes: field("Shmem"),
+            anonymous_bytes: field("AnonPages"),
+            slab_bytes: field("Slab"),
+            slab_reclaimable_bytes: field("SReclaimable"),
+            slab_unreclaimable_bytes: field("SUnreclaim"),
+            dirty_bytes: field("Dirty"),
+            writeback_bytes: field("Writeback"),
+            committed_bytes: field("Committed_AS"),
+            commit_limit_bytes: field("CommitLimit"),
+            anonymous_hugepages_bytes: field("AnonHugePages"),
+            hugepages_total: field("HugePages_Total"),
+            hugepages_free: field("HugePages_Free"),
+            hugepage_size_bytes: field("Hugepagesize"),
+        }
+    }
+}
+
+///以RAM结构返回系统的RAM，`detailed` 为真时附加 `/proc/meminfo` 中的内存明细
+pub async fn ram(detailed: bool) -> Result<Ram> {
     let mut system = System::new_with_specifics(RefreshKind::new().with_memory());
     system.refresh_system();
 
+    let swap_devices = match tokio::task::spawn_blocking(|| std::fs::read_to_string(PROC_SWAPS)).await? {
+        Ok(contents) => parse_swaps(&contents),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(e).with_context(|| format!("reading {} failed", PROC_SWAPS)),
+    };
+
+    let breakdown = if detailed {
+        let meminfo = tokio::task::spawn_blocking(|| std::fs::read_to_string(PROC_MEMINFO)).await?
+            .with_context(|| format!("reading {} failed", PROC_MEMINFO))?;
+        Some(MemoryBreakdown::from(&parse_meminfo(&meminfo)))
+    } else {
+        None
+    };
+
+    let swap_total = system.total_swap();
+    let swap_used = system.used_swap();
+
     Ok(Ram{
         total:system.total_memory(),
         used:system.used_memory(),
         free:system.free_memory(),
         available:system.available_memory(),
+        swap_total,
+        swap_used,
+        swap_free:system.free_swap(),
+        swap_heavily_used: swap_total > 0 && (swap_used as f64 / swap_total as f64) * 100.0 > SWAP_HEAVY_USE_PERCENTAGE,
+        swap_devices,
+        breakdown,
     })
 }
+
+/// Linux 压力停滞信息（PSI）所在的目录
+pub const PROC_PRESSURE: &str = "/proc/pressure";
+
+/// 检查的PSI资源
+pub const PRESSURE_RESOURCES: [&str; 3] = ["cpu", "memory", "io"];
+
+/// 1、5和15分钟的平均负载
+#[derive(Serialize, Clone, Copy)]
+pub struct LoadAverages {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+}
+
+impl Display for LoadAverages {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.2} {:.2} {:.2}", self.one, self.five, self.fifteen)
+    }
+}
+
+/// PSI的10秒、60秒和300秒平均值，单位为百分比
+#[derive(Serialize, Clone, Copy, PartialEq)]
+pub struct PressureAverages {
+    pub avg10: f64,
+    pub avg60: f64,
+    pub avg300: f64,
+
+    // 累计停滞时间，单位为微秒
+    pub total_us: u64,
+}
+
+impl Display for PressureAverages {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}% {}% {}%",
+            usage_colored(self.avg10),
+            usage_colored(self.avg60),
+            usage_colored(self.avg300),
+        )
+    }
+}
+
+/// 一种资源的压力
+#[derive(Serialize)]
+pub struct Pressure {
+
+    // 资源名称：cpu、memory 或 io
+    pub resource: String,
+
+    // 至少有一个任务因该资源停滞的时间比例
+    pub some: PressureAverages,
+
+    // 所有非空闲任务同时因该资源停滞的时间比例
+    pub full: Option<PressureAverages>,
+}
+
+impl Display for Pressure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\tsome {}", self.resource.bold(), self.some)?;
+        if let Some(full) = &self.full {
+            write!(f, "\tfull {}", full)?;
+        }
+        Ok(())
+    }
+}
+
+/// 系统负载
+#[derive(Serialize)]
+pub struct Load {
+
+    // 逻辑核心数量
+    pub logical_core_count: usize,
+
+    // 平均负载
+    pub load: LoadAverages,
+
+    // 按逻辑核心数量归一化的平均负载，1.0 表示所有核心都已满载
+    pub load_per_cpu: LoadAverages,
+
+    // PSI平均值，内核不支持PSI或读取失败时为空
+    pub pressure: Vec<Pressure>,
+
+    // PSI不可用的原因，用于区分内核不支持PSI和读取失败
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pressure_unavailable: Option<String>,
+}
+
+impl Display for Load {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{} ({}% {}% {}% of {} CPUs)",
+            "load".bold(),
+            self.load,
+            usage_colored(self.load_per_cpu.one * 100.0),
+            usage_colored(self.load_per_cpu.five * 100.0),
+            usage_colored(self.load_per_cpu.fifteen * 100.0),
+            self.logical_core_count.to_string().cyan(),
+        )?;
+
+        for pressure in &self.pressure {
+            write!(f, "\n{}", pressure)?;
+        }
+        if let Some(reason) = &self.pressure_unavailable {
+            write!(f, "\n{}\t{} ({})", "pressure".bold(), "unavailable".yellow(), reason)?;
+        }
+        Ok(())
+    }
+}
+
+/// 解析 `/proc/pressure/<resource>` 的内容，返回 `some` 和 `full` 两行的平均值
+pub fn parse_pressure(contents: &str) -> Option<(PressureAverages, Option<PressureAverages>)> {
+    let mut some = None;
+    let mut full = None;
+
+    for line in contents.lines() {
+        let mut fields = line.split_whitespace();
+        let kind = fields.next()?;
+
+        let mut averages = PressureAverages { avg10: 0.0, avg60: 0.0, avg300: 0.0, total_us: 0 };
+        for field in fields {
+            match field.split_once('=')? {
+                ("avg10", value) => averages.avg10 = value.parse().ok()?,
+                ("avg60", value) => averages.avg60 = value.parse().ok()?,
+                ("avg300", value) => averages.avg300 = value.parse().ok()?,
+                ("total", value) => averages.total_us = value.parse().ok()?,
+                _ => {}
+            }
+        }
+
+        match kind {
+            "some" => some = Some(averages),
+            "full" => full = Some(averages),
+            _ => {}
+        }
+    }
+
+    Some((some?, full))
+}
+
+/// 读取各资源的PSI平均值。
+/// `/proc/pressure` 不存在说明内核没有启用PSI；其他读取或解析失败时返回原因，而不是静默忽略。
+pub fn read_pressure(root: &Path) -> (Vec<Pressure>, Option<String>) {
+    if !root.exists() {
+        return (Vec::new(), Some("not supported by the kernel".to_string()));
+    }
+
+    let mut pressure = Vec::new();
+    let mut errors = Vec::new();
+    for resource in PRESSURE_RESOURCES {
+        let path = root.join(resource);
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => match parse_pressure(&contents) {
+                Some((some, full)) => pressure.push(Pressure { resource: resource.to_string(), some, full }),
+                None => errors.push(format!("{} has an unexpected format", path.display())),
+            },
+            Err(e) => errors.push(format!("reading {} failed: {}", path.display(), e)),
+        }
+    }
+
+    let unavailable = if errors.is_empty() { None } else { Some(errors.join("; ")) };
+    (pressure, unavailable)
+}
+
+/// 返回系统的平均负载以及PSI平均值。
+/// 内核没有启用PSI或读取失败时只返回平均负载，并在 `pressure_unavailable` 中说明原因。
+pub async fn load() -> Result<Load> {
+    let system = System::new_with_specifics(RefreshKind::new().with_cpu(CpuRefreshKind::new()));
+    let logical_core_count = system.cpus().len().max(1);
+
+    let average = system.load_average();
+    let load = LoadAverages { one: average.one, five: average.five, fifteen: average.fifteen };
+    let load_per_cpu = LoadAverages {
+        one: load.one / logical_core_count as f64,
+        five: load.five / logical_core_count as f64,
+        fifteen: load.fifteen / logical_core_count as f64,
+    };
+
+    let (pressure, pressure_unavailable) = tokio::task::spawn_blocking(|| read_pressure(Path::new(PROC_PRESSURE))).await?;
+
+    Ok(Load { logical_core_count, load, load_per_cpu, pressure, pressure_unavailable })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_cpu_flags_reads_the_first_processor() {
+        let x86 = "processor\t: 0\nvendor_id\t: GenuineIntel\nflags\t\t: fpu sse2 avx2\n\n\
+                   processor\t: 1\nflags\t\t: fpu\n";
+        assert_eq!(parse_cpu_flags(x86), (vec!["fpu".to_string(), "sse2".to_string(), "avx2".to_string()], true));
+
+        let arm = "processor\t: 0\nBogoMIPS\t: 48.00\nFeatures\t: fp asimd aes crc32\nCPU implementer\t: 0x41\n";
+        assert_eq!(parse_cpu_flags(arm), (vec!["fp".to_string(), "asimd".to_string(), "aes".to_string(), "crc32".to_string()], false));
+
+        assert_eq!(parse_cpu_flags("processor\t: 0\n"), (Vec::new(), false));
+    }
+
+    #[test]
+    fn x86_64_level_stops_at_the_first_incomplete_level() {
+        let flags_up_to = |level: u8| {
+            X86_64_LEVELS
+                .iter()
+                .filter(|(next, _)| *next <= level)
+                .flat_map(|(_, required)| required.iter().map(|flag| flag.to_string()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(x86_64_level(&[]), 1);
+        assert_eq!(x86_64_level(&flags_up_to(2)), 2);
+        assert_eq!(x86_64_level(&flags_up_to(3)), 3);
+        assert_eq!(x86_64_level(&flags_up_to(4)), 4);
+
+        let without = |level: u8, missing: &str| flags_up_to(level).into_iter().filter(|flag| flag != missing).collect::<Vec<_>>();
+        assert_eq!(x86_64_level(&without(2, "popcnt")), 1);
+        assert_eq!(x86_64_level(&without(3, "movbe")), 2);
+        assert_eq!(x86_64_level(&without(4, "avx512vl")), 3);
+        // 缺少较低级别的标志时，较高级别的标志不起作用
+        assert_eq!(x86_64_level(&without(4, "ssse3")), 1);
+    }
+
+    #[test]
+    fn parse_cache_size_reads_sysfs_suffixes() {
+        assert_eq!(parse_cache_size("48K\n"), Some(48 * 1024));
+        assert_eq!(parse_cache_size("2M"), Some(2 * 1024 * 1024));
+        assert_eq!(parse_cache_size("1G"), Some(1024 * 1024 * 1024));
+        assert_eq!(parse_cache_size("512"), Some(512));
+        assert_eq!(parse_cache_size("12KB"), None);
+        assert_eq!(parse_cache_size("K"), None);
+        assert_eq!(parse_cache_size(""), None);
+    }
+
+    #[test]
+    fn parse_swaps_reads_devices_and_converts_to_bytes() {
+        let contents = "Filename\t\t\t\tType\t\tSize\t\tUsed\t\tPriority\n\
+                        /dev/nvme0n1p3                          partition\t8388604\t\t1024\t\t-2\n\
+                        /swap\\040file                            file\t\t1048572\t\t0\t\t10\n\
+                        /broken                                 file\n";
+        let devices = parse_swaps(contents);
+        assert_eq!(devices.len(), 2);
+        assert_eq!(devices[0].path, "/dev/nvme0n1p3");
+        assert_eq!(devices[0].kind, "partition");
+        assert_eq!(devices[0].size_bytes, 8388604 * 1024);
+        assert_eq!(devices[0].used_bytes, 1024 * 1024);
+        assert_eq!(devices[0].priority, -2);
+        assert_eq!(devices[1].path, "/swap file");
+        assert_eq!(devices[1].priority, 10);
+        assert!(parse_swaps("Filename\tType\tSize\tUsed\tPriority\n").is_empty());
+    }
+
+    #[test]
+    fn parse_meminfo_converts_kilobytes_and_keeps_counts() {
+        let contents = "MemTotal:       16303196 kB\n\
+                        Buffers:          123456 kB\n\
+                        SReclaimable:       2048 kB\n\
+                        HugePages_Total:       4\n\
+                        Hugepagesize:       2048 kB\n\
+                        Garbage line\n";
+        let meminfo = parse_meminfo(contents);
+        assert_eq!(meminfo.get("MemTotal"), Some(&(16303196 * 1024)));
+        assert_eq!(meminfo.get("HugePages_Total"), Some(&4));
+        assert_eq!(meminfo.len(), 5);
+
+        let breakdown = MemoryBreakdown::from(&meminfo);
+        assert_eq!(breakdown.buffers_bytes, 123456 * 1024);
+        assert_eq!(breakdown.slab_reclaimable_bytes, 2048 * 1024);
+        assert_eq!(breakdown.hugepages_total, 4);
+        assert_eq!(breakdown.hugepage_size_bytes, 2048 * 1024);
+        assert_eq!(breakdown.dirty_bytes, 0);
+    }
+
+    const PRESSURE: &str = "some avg10=1.50 avg60=0.75 avg300=0.25 total=123456\n\
+                            full avg10=0.50 avg60=0.00 avg300=0.10 total=6789\n";
+
+    #[test]
+    fn parse_pressure_reads_some_and_full() {
+        let (some, full) = parse_pressure(PRESSURE).unwrap();
+        assert!(some == PressureAverages { avg10: 1.5, avg60: 0.75, avg300: 0.25, total_us: 123456 });
+        assert!(full == Some(PressureAverages { avg10: 0.5, avg60: 0.0, avg300: 0.1, total_us: 6789 }));
+    }
+
+    #[test]
+    fn parse_pressure_allows_a_missing_full_line() {
+        let (_, full) = parse_pressure("some avg10=0.00 avg60=0.00 avg300=0.00 total=0\n").unwrap();
+        assert!(full.is_none());
+    }
+
+    #[test]
+    fn parse_pressure_rejects_malformed_contents() {
+        assert!(parse_pressure("").is_none());
+        assert!(parse_pressure("some avg10=abc avg60=0.00 avg300=0.00 total=0\n").is_none());
+        assert!(parse_pressure("full avg10=0.00 avg60=0.00 avg300=0.00 total=0\n").is_none());
+    }
+
+    #[test]
+    fn read_pressure_tells_missing_psi_from_failed_reads() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let (pressure, unavailable) = read_pressure(&dir.path().join("pressure"));
+        assert!(pressure.is_empty());
+        assert_eq!(unavailable.as_deref(), Some("not supported by the kernel"));
+
+        std::fs::write(dir.path().join("cpu"), PRESSURE).unwrap();
+        std::fs::write(dir.path().join("memory"), "garbage\n").unwrap();
+        let (pressure, unavailable) = read_pressure(dir.path());
+        assert_eq!(pressure.len(), 1);
+        assert_eq!(pressure[0].resource, "cpu");
+        let unavailable = unavailable.unwrap();
+        assert!(unavailable.contains("memory has an unexpected format"), "{}", unavailable);
+        assert!(unavailable.contains("io failed"), "{}", unavailable);
+    }
+}
@@ -0,0 +1,482 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use anyhow::{bail, Context, Result};
+use colored::*;
+use serde::Serialize;
+use tokio::task::spawn_blocking;
+
+/// 内核无线网口状态文件
+pub const PROC_NET_WIRELESS: &str = "/proc/net/wireless";
+
+/// 无线网口的连接详情
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+pub struct WirelessLink {
+    /// 无线网口名称
+    pub interface: String,
+
+    /// 已连接网络的名称
+    pub ssid: Option<String>,
+
+    /// 已连接接入点的硬件地址
+    pub bssid: Option<String>,
+
+    /// 工作频率，单位为MHz
+    pub frequency_mhz: Option<u32>,
+
+    /// 由工作频率推算的信道
+    pub channel: Option<u32>,
+
+    /// 信号强度，单位为dBm
+    pub signal_dbm: Option<i32>,
+
+    /// 驱动报告的链路质量
+    pub link_quality: Option<f64>,
+
+    /// 发送速率，单位为Mbit/s
+    pub tx_bitrate_mbps: Option<f64>,
+}
+
+impl Display for WirelessLink {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.interface.bold())?;
+
+        match &self.ssid {
+            Some(ssid) => write!(f, "\t{}", ssid.cyan().bold())?,
+            None => write!(f, "\t{}", "not connected".yellow())?,
+        }
+        if let Some(bssid) = &self.bssid {
+            write!(f, "\t{}", bssid)?;
+        }
+        if let Some(frequency) = self.frequency_mhz {
+            write!(f, "\t{} MHz", frequency)?;
+            if let Some(channel) = self.channel {
+                write!(f, " (channel {})", channel)?;
+            }
+        }
+        if let Some(signal) = self.signal_dbm {
+            let text = format!("{} dBm", signal);
+            let colored = match signal {
+                _ if signal < -75 => text.red(),
+                _ if signal < -65 => text.yellow(),
+                _ => text.green(),
+            };
+            write!(f, "\t{}", colored)?;
+        }
+        if let Some(bitrate) = self.tx_bitrate_mbps {
+            write!(f, "\t{:.1} Mbit/s", bitrate)?;
+        }
+        Ok(())
+    }
+}
+
+/// 根据工作频率推算Wi-Fi信道
+pub fn channel(frequency_mhz: u32) -> Option<u32> {
+    match frequency_mhz {
+        2484 => Some(14),
+        2412..=2472 => Some((frequency_mhz - 2407) / 5),
+        5955..=7115 => Some((frequency_mhz - 5950) / 5),
+        5160..=5885 => Some((frequency_mhz - 5000) / 5),
+        _ => None,
+    }
+}
+
+/// 解析 `/proc/net/wireless` 的内容，返回每个无线网口的链路质量和信号强度。
+///
+/// # Errors
+///
+/// 如果某一行的字段缺失或不是合法的数字。
+pub fn parse_proc_wireless(contents: &str) -> Result<HashMap<String, (f64, f64)>> {
+    contents
+        .lines()
+        // 前两行是表头
+        .skip(2)
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, values)| {
+            let values = values.split_whitespace().collect::<Vec<_>>();
+            if values.len() < 3 {
+                bail!("malformed wireless entry for {}", name.trim());
+            }
+
+            // 驱动在数值后追加 '.' 表示该值已更新
+            let number = |value: &str| value
+                .trim_end_matches('.')
+                .parse::<f64>()
+                .with_context(|| format!("invalid wireless value {:?} for {}", value, name.trim()));
+
+            Ok((name.trim().to_string(), (number(values[1])?, number(values[2])?)))
+        })
+        .collect()
+}
+
+/// 列出所有无线网口的连接详情。
+/// 链路质量读取自 `/proc/net/wireless`，SSID、BSSID、频率和速率通过 nl80211 查询。
+/// nl80211 不可用时（例如没有加载无线驱动）只返回 `/proc/net/wireless` 中的信息。
+///
+/// # Errors
+///
+/// 如果无法读取或解析 `/proc/net/wireless`。
+pub async fn links() -> Result<Vec<WirelessLink>> {
+    spawn_blocking(|| {
+        let proc_wireless = match std::fs::read_to_string(PROC_NET_WIRELESS) {
+            Ok(contents) => parse_proc_wireless(&contents)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(e).with_context(|| format!("reading {} failed", PROC_NET_WIRELESS)),
+        };
+        let nl80211_links = nl80211_links().unwrap_or_default();
+
+        let mut names = proc_wireless.keys().chain(nl80211_links.keys()).cloned().collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+
+        Ok(names
+            .into_iter()
+            .map(|name| {
+                let mut link = nl80211_links.get(&name).cloned().unwrap_or_default();
+                if let Some((quality, level)) = proc_wireless.get(&name) {
+                    link.link_quality = Some(*quality);
+                    link.signal_dbm = link.signal_dbm.or(Some(*level as i32));
+                }
+                link.channel = link.frequency_mhz.and_then(channel);
+                link.interface = name;
+                link
+            })
+            .collect())
+    }).await?
+}
+
+/// 通过 nl80211 查询无线网口的连接信息
+#[cfg(target_os = "linux")]
+fn nl80211_links() -> Result<HashMap<String, WirelessLink>> {
+    use nl80211::*;
+
+    let mut socket = GenlSocket::new()?;
+    let family = socket.family_id("nl80211")?;
+
+    let mut links = HashMap::new();
+    for message in socket.request(family, NL80211_CMD_GET_INTERFACE, NLM_F_DUMP, &[])? {
+        let interface = attributes(&message);
+        let (Some(ifindex), Some(name)) = (
+            interface.get(&NL80211_ATTR_IFINDEX).and_then(|value| read_u32(value)),
+            interface.get(&NL80211_ATTR_IFNAME).map(|value| read_string(value)),
+        ) else {
+            continue;
+        };
+
+        let mut link = WirelessLink {
+            interface: name.clone(),
+            ssid: interface.get(&NL80211_ATTR_SSID).map(|value| String::from_utf8_lossy(value).into_owned()),
+            frequency_mhz: interface.get(&NL80211_ATTR_WIPHY_FREQ).and_then(|value| read_u32(value)),
+            ..Default::default()
+        };
+
+        // 客户端模式下，站点列表中只有已连接的接入点
+        let stations = socket.request(
+            family,
+            NL80211_CMD_GET_STATION,
+            NLM_F_DUMP,
+            &[(NL80211_ATTR_IFINDEX, &ifindex.to_ne_bytes())],
+        );
+        if let Some(station) = stations.ok().and_then(|stations| stations.into_iter().next()) {
+            let station = attributes(&station);
+            link.bssid = station.get(&NL80211_ATTR_MAC).map(|mac| {
+                mac.iter().map(|byte| format!("{:02x}", byte)).collect::<Vec<_>>().join(":")
+            });
+
+            if let Some(info) = station.get(&NL80211_ATTR_STA_INFO).map(|info| attributes(info)) {
+                link.signal_dbm = info.get(&NL80211_STA_INFO_SIGNAL).and_then(|value| value.first()).map(|&signal| signal as i8 as i32);
+                link.tx_bitrate_mbps = info.get(&NL80211_STA_INFO_TX_BITRATE).and_then(|rate| {
+                    let rate = attributes(rate);
+                    // 速率以 100kbit/s 为单位
+                    rate.get(&NL80211_RATE_INFO_BITRATE32)
+                        .and_then(|value| read_u32(value))
+                        .or_else(|| rate.get(&NL80211_RATE_INFO_BITRATE).and_then(|value| read_u16(value)).map(u32::from))
+                        .map(|rate| rate as f64 / 10.0)
+                });
+            }
+        }
+
+        links.insert(name, link);
+    }
+
+    Ok(links)
+}
+
+/// 其他平台没有 nl80211
+#[cfg(not(target_os = "linux"))]
+fn nl80211_links() -> Result<HashMap<String, WirelessLink>> {
+    Ok(HashMap::new())
+}
+
+/// 基于 netlink-packet-core 的 generic netlink 客户端，只实现查询 nl80211 所需的部分
+#[cfg(target_os = "linux")]
+mod nl80211 {
+    use std::collections::HashMap;
+    use anyhow::{bail, Context, Result};
+    use netlink_packet_core::{
+        NetlinkDeserializable, NetlinkHeader, NetlinkMessage, NetlinkPayload, NetlinkSerializable, NLM_F_MULTIPART,
+        NLM_F_REQUEST,
+    };
+    use netlink_packet_utils::nla::{DefaultNla, NlasIterator};
+    use netlink_packet_utils::{DecodeError, Emitable};
+    use netlink_sys::{protocols::NETLINK_GENERIC, Socket, SocketAddr};
+
+    pub use netlink_packet_core::NLM_F_DUMP;
+
+    const GENL_ID_CTRL: u16 = 0x10;
+    const CTRL_CMD_GETFAMILY: u8 = 3;
+    const CTRL_ATTR_FAMILY_ID: u16 = 1;
+    const CTRL_ATTR_FAMILY_NAME: u16 = 2;
+
+    pub const NL80211_CMD_GET_INTERFACE: u8 = 5;
+    pub const NL80211_CMD_GET_STATION: u8 = 17;
+    pub const NL80211_ATTR_IFINDEX: u16 = 3;
+    pub const NL80211_ATTR_IFNAME: u16 = 4;
+    pub const NL80211_ATTR_MAC: u16 = 6;
+    pub const NL80211_ATTR_STA_INFO: u16 = 21;
+    pub const NL80211_ATTR_WIPHY_FREQ: u16 = 38;
+    pub const NL80211_ATTR_SSID: u16 = 52;
+    pub const NL80211_STA_INFO_SIGNAL: u16 = 7;
+    pub const NL80211_STA_INFO_TX_BITRATE: u16 = 8;
+    pub const NL80211_RATE_INFO_BITRATE: u16 = 1;
+    pub const NL80211_RATE_INFO_BITRATE32: u16 = 5;
+
+    /// generic netlink 头部的长度：命令、版本和两个保留字节
+    const GENL_HEADER_LEN: usize = 4;
+    const GENL_VERSION: u8 = 1;
+
+    /// 一条 generic netlink 消息。netlink 头部的消息类型即协议族的ID。
+    struct GenlMessage {
+        family: u16,
+        command: u8,
+        /// 请求中的属性
+        nlas: Vec<DefaultNla>,
+        /// 应答中未解析的属性
+        payload: Vec<u8>,
+    }
+
+    impl NetlinkSerializable for GenlMessage {
+        fn message_type(&self) -> u16 {
+            self.family
+        }
+
+        fn buffer_len(&self) -> usize {
+            GENL_HEADER_LEN + self.nlas.as_slice().buffer_len()
+        }
+
+        fn serialize(&self, buffer: &mut [u8]) {
+            buffer[..GENL_HEADER_LEN].copy_from_slice(&[self.command, GENL_VERSION, 0, 0]);
+            self.nlas.as_slice().emit(&mut buffer[GENL_HEADER_LEN..]);
+        }
+    }
+
+    impl NetlinkDeserializable for GenlMessage {
+        type Error = DecodeError;
+
+        fn deserialize(header: &NetlinkHeader, payload: &[u8]) -> Result<Self, DecodeError> {
+            if payload.len() < GENL_HEADER_LEN {
+                return Err("truncated generic netlink header".into());
+            }
+            Ok(GenlMessage {
+                family: header.message_type,
+                command: payload[0],
+                nlas: Vec::new(),
+                payload: payload[GENL_HEADER_LEN..].to_vec(),
+            })
+        }
+    }
+
+    pub struct GenlSocket {
+        socket: Socket,
+        sequence: u32,
+    }
+
+    impl GenlSocket {
+        pub fn new() -> Result<Self> {
+            let mut socket = Socket::new(NETLINK_GENERIC)?;
+            socket.bind_auto()?;
+            socket.connect(&SocketAddr::new(0, 0))?;
+            Ok(GenlSocket { socket, sequence: 0 })
+        }
+
+        /// 查询 generic netlink 协议族的ID
+        pub fn family_id(&mut self, name: &str) -> Result<u16> {
+            let mut family_name = name.as_bytes().to_vec();
+            family_name.push(0);
+
+            let replies = self.request(GENL_ID_CTRL, CTRL_CMD_GETFAMILY, 0, &[(CTRL_ATTR_FAMILY_NAME, &family_name)])?;
+            replies
+                .first()
+                .and_then(|reply| attributes(reply).get(&CTRL_ATTR_FAMILY_ID).and_then(|value| read_u16(value)))
+                .with_context(|| format!("generic netlink family {} not found", name))
+        }
+
+        /// 发送请求并收集所有应答，返回每条应答去掉头部后的属性部分
+        pub fn request(&mut self, family: u16, command: u8, flags: u16, attributes: &[(u16, &[u8])]) -> Result<Vec<Vec<u8>>> {
+            self.sequence += 1;
+
+            let mut header = NetlinkHeader::default();
+            header.flags = NLM_F_REQUEST | flags;
+            header.sequence_number = self.sequence;
+            let message = GenlMessage {
+                family,
+                command,
+                nlas: attributes.iter().map(|(kind, value)| DefaultNla::new(*kind, value.to_vec())).collect(),
+                payload: Vec::new(),
+            };
+
+            let mut request = NetlinkMessage::new(header, NetlinkPayload::InnerMessage(message));
+            request.finalize();
+            let mut buffer = vec![0; request.buffer_len()];
+            request.serialize(&mut buffer);
+            self.socket.send(&buffer, 0)?;
+
+            let mut replies = Vec::new();
+            let mut receive_buffer = vec![0; 32 * 1024];
+            loop {
+                let size = self.socket.recv(&mut &mut receive_buffer[..], 0)?;
+                let mut offset = 0;
+                let mut multipart = false;
+
+                while offset < size {
+                    let response = NetlinkMessage::<GenlMessage>::deserialize(&receive_buffer[offset..size])
+                        .map_err(|e| anyhow::anyhow!("invalid netlink message: {}", e))?;
+                    if response.header.length == 0 {
+                        break;
+                    }
+                    offset += response.header.length as usize;
+                    multipart |= response.header.flags & NLM_F_MULTIPART != 0;
+
+                    match response.payload {
+                        NetlinkPayload::Done(_) => return Ok(replies),
+                        NetlinkPayload::Error(e) => match e.code {
+                            Some(code) => return Err(std::io::Error::from_raw_os_error(-code.get()).into()),
+                            None => return Ok(replies),
+                        },
+                        NetlinkPayload::InnerMessage(reply) => replies.push(reply.payload),
+                        NetlinkPayload::Overrun(_) => bail!("netlink receive buffer overrun"),
+                        _ => {}
+                    }
+                }
+
+                if !multipart {
+                    return Ok(replies);
+                }
+            }
+        }
+    }
+
+    /// 解析一组 netlink 属性，按属性类型索引。遇到不完整的属性时停止解析。
+    pub fn attributes(bytes: &[u8]) -> HashMap<u16, &[u8]> {
+        NlasIterator::new(bytes)
+            .map_while(Result::ok)
+            .map(|nla| {
+                let (kind, length) = (nla.kind(), usize::from(nla.length()));
+                (kind, &nla.into_inner()[4..length])
+            })
+            .collect()
+    }
+
+    pub fn read_u16(bytes: &[u8]) -> Option<u16> {
+        Some(u16::from_ne_bytes(bytes.get(..2)?.try_into().ok()?))
+    }
+
+    pub fn read_u32(bytes: &[u8]) -> Option<u32> {
+        Some(u32::from_ne_bytes(bytes.get(..4)?.try_into().ok()?))
+    }
+
+    /// 读取以 NUL 结尾的字符串
+    pub fn read_string(bytes: &[u8]) -> String {
+        String::from_utf8_lossy(bytes.split(|&byte| byte == 0).next().unwrap_or_default()).into_owned()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn request_attributes_round_trip_through_attributes() {
+            let message = GenlMessage {
+                family: GENL_ID_CTRL,
+                command: CTRL_CMD_GETFAMILY,
+                nlas: vec![
+                    DefaultNla::new(CTRL_ATTR_FAMILY_NAME, b"nl80211\0".to_vec()),
+                    DefaultNla::new(CTRL_ATTR_FAMILY_ID, vec![1, 2]),
+                ],
+                payload: Vec::new(),
+            };
+            let mut buffer = vec![0; message.buffer_len()];
+            message.serialize(&mut buffer);
+
+            assert_eq!(&buffer[..GENL_HEADER_LEN], &[CTRL_CMD_GETFAMILY, GENL_VERSION, 0, 0]);
+            let parsed = attributes(&buffer[GENL_HEADER_LEN..]);
+            assert_eq!(read_string(parsed[&CTRL_ATTR_FAMILY_NAME]), "nl80211");
+            assert_eq!(parsed[&CTRL_ATTR_FAMILY_ID], &[1, 2]);
+        }
+
+        #[test]
+        fn attributes_stops_at_a_truncated_attribute() {
+            // 第二个属性声明的长度超出了缓冲区
+            let bytes = [8, 0, 3, 0, 7, 0, 0, 0, 12, 0, 4, 0, 1];
+            let parsed = attributes(&bytes);
+
+            assert_eq!(parsed.len(), 1);
+            assert_eq!(read_u32(parsed[&NL80211_ATTR_IFINDEX]), Some(7));
+        }
+
+        #[test]
+        #[ignore = "needs a generic netlink socket, which sandboxes may not allow"]
+        fn family_id_resolves_the_controller_family() {
+            let mut socket = GenlSocket::new().unwrap();
+            assert_eq!(socket.family_id("nlctrl").unwrap(), GENL_ID_CTRL);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn channel_covers_each_band() {
+        let cases = [
+            (2412, Some(1)),
+            (2437, Some(6)),
+            (2472, Some(13)),
+            (2484, Some(14)),
+            (5180, Some(36)),
+            (5500, Some(100)),
+            (5825, Some(165)),
+            (5955, Some(1)),
+            (6115, Some(33)),
+            (7115, Some(233)),
+            (2400, None),
+            (4900, None),
+            (60480, None),
+        ];
+        for (frequency, expected) in cases {
+            assert_eq!(channel(frequency), expected, "{} MHz", frequency);
+        }
+    }
+
+    #[test]
+    fn parse_proc_wireless_reads_quality_and_level() {
+        let contents = "\
+Inter-| sta-|   Quality        |   Discarded packets               | Missed | WE
+ face | tus | link level noise |  nwid  crypt   frag  retry   misc | beacon | 22
+wlp2s0: 0000   58.  -52.  -256        0      0      0      5    112        0
+  wlan1: 0000   70   -40   -256        0      0      0      0      0        0
+";
+        let links = parse_proc_wireless(contents).unwrap();
+        assert_eq!(links.len(), 2);
+        assert_eq!(links["wlp2s0"], (58.0, -52.0));
+        assert_eq!(links["wlan1"], (70.0, -40.0));
+    }
+
+    #[test]
+    fn parse_proc_wireless_rejects_malformed_lines() {
+        let header = "Inter-| sta-|   Quality\n face | tus | link level noise\n";
+        assert!(parse_proc_wireless(header).unwrap().is_empty());
+        assert!(parse_proc_wireless(&format!("{}wlan0: 0000 58.\n", header)).is_err());
+        assert!(parse_proc_wireless(&format!("{}wlan0: 0000 good. -52. -256\n", header)).is_err());
+    }
+}